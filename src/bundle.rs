@@ -0,0 +1,138 @@
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::config::Config;
+
+/// Format version written into every bundle; bumped on incompatible changes.
+pub const BUNDLE_VERSION: u32 = 1;
+
+// ── data model ────────────────────────────────────────────────────────────────
+
+/// A portable slice of a dimos config, shared between machines/teammates.
+///
+/// Only answers travel — `init_completed` describes one machine and is never
+/// exported.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Bundle {
+    pub dimos_bundle: u32,
+    /// Answers keyed by QuestionKey::ADDRESS, sorted for stable diffs.
+    #[serde(default)]
+    pub answers: BTreeMap<String, String>,
+}
+
+/// Which part of the config a bundle carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Section {
+    /// Survey answers (everything except new-app defaults)
+    Answers,
+    /// `new_app.*` defaults used to pre-fill `dimos new-app`
+    Defaults,
+}
+
+impl Section {
+    pub fn contains(self, address: &str) -> bool {
+        let is_default = address.starts_with("new_app.");
+        match self {
+            Section::Answers => !is_default,
+            Section::Defaults => is_default,
+        }
+    }
+}
+
+impl Bundle {
+    pub fn export(config: &Config, only: Option<Section>) -> Self {
+        let answers = config
+            .answers
+            .iter()
+            .filter(|(k, _)| only.is_none_or(|s| s.contains(k)))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        Self {
+            dimos_bundle: BUNDLE_VERSION,
+            answers,
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        let bundle: Self = serde_yaml::from_str(&text)
+            .with_context(|| format!("{} is not a dimos bundle", path.display()))?;
+        if bundle.dimos_bundle > BUNDLE_VERSION {
+            bail!(
+                "{} uses bundle format {}, this dimos only understands up to {BUNDLE_VERSION} — upgrade dimos.",
+                path.display(),
+                bundle.dimos_bundle
+            );
+        }
+        Ok(bundle)
+    }
+}
+
+/// What `dimos config import` does when a bundle disagrees with a local answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OnConflict {
+    /// Ask per key (requires a terminal)
+    Ask,
+    /// Keep the local answer
+    Keep,
+    /// Take the bundle's answer
+    Take,
+}
+
+// ── diff ──────────────────────────────────────────────────────────────────────
+
+/// How importing a bundle would change one answer. Identical values are
+/// omitted from the diff entirely.
+#[derive(Debug)]
+pub enum Change {
+    /// Key not present locally.
+    Add { key: String, value: String },
+    /// Key present locally with a different value.
+    Conflict {
+        key: String,
+        ours: String,
+        theirs: String,
+    },
+}
+
+impl Change {
+    pub fn key(&self) -> &str {
+        match self {
+            Change::Add { key, .. } | Change::Conflict { key, .. } => key,
+        }
+    }
+}
+
+pub fn diff(config: &Config, bundle: &Bundle) -> Vec<Change> {
+    bundle
+        .answers
+        .iter()
+        .filter_map(|(key, theirs)| match config.answers.get(key) {
+            None => Some(Change::Add {
+                key: key.clone(),
+                value: theirs.clone(),
+            }),
+            Some(ours) if ours != theirs => Some(Change::Conflict {
+                key: key.clone(),
+                ours: ours.clone(),
+                theirs: theirs.clone(),
+            }),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+/// Render a diff for a `cliclack::note` preview.
+pub fn format_diff(changes: &[Change]) -> String {
+    changes
+        .iter()
+        .map(|c| match c {
+            Change::Add { key, value } => format!("+ {key}: {value}"),
+            Change::Conflict { key, ours, theirs } => format!("~ {key}: {ours} → {theirs}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use std::{env, process::Command};
+use which::which;
+
+use crate::config::Config;
+use super::{Autofix, Check};
+
+/// Needed by `rust-ext` projects, whose extension maturin builds with cargo.
+/// rustup installs the same way on all platforms.
+pub fn check() -> Check {
+    Check {
+        label: "cargo",
+        detect,
+        fix_instructions: Some(fix_instructions),
+        autofix: Some(Autofix {
+            prompt: "Install Rust via rustup (rustup.rs)?",
+            run: autofix,
+        }),
+    }
+}
+
+fn detect(_cfg: &Config) -> bool {
+    which("cargo").is_ok()
+}
+
+fn fix_instructions(_cfg: &Config) -> String {
+    "Install Rust with rustup: https://rustup.rs".to_string()
+}
+
+fn autofix(_cfg: &Config) -> Result<()> {
+    let status = Command::new("sh")
+        .args([
+            "-c",
+            "curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh -s -- -y",
+        ])
+        .status()?;
+
+    if !status.success() {
+        anyhow::bail!("Failed to install Rust — install it with rustup and try again.");
+    }
+
+    // rustup adds ~/.cargo/bin to shell profiles, which this process never
+    // re-reads; put it on PATH for the build that follows.
+    if let Some(home) = dirs::home_dir() {
+        let path = env::var_os("PATH").unwrap_or_default();
+        let paths = std::iter::once(home.join(".cargo").join("bin")).chain(env::split_paths(&path));
+        env::set_var("PATH", env::join_paths(paths)?);
+    }
+    Ok(())
+}
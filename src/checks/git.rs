@@ -12,11 +12,14 @@ fn fix_instructions(_cfg: &Config) -> String {
     "Install git via your package manager (apt/brew/nix).".to_string()
 }
 
+// Not wired into any install sequence yet.
+#[allow(dead_code)]
 pub mod apt {
     use super::*;
+    pub const PACKAGE: &str = "git";
     pub fn check() -> Check {
         Check {
-            label: "git",
+            label: PACKAGE,
             detect,
             fix_instructions: Some(fix_instructions),
             autofix: Some(Autofix {
@@ -26,7 +29,7 @@ pub mod apt {
         }
     }
     fn autofix(_cfg: &Config) -> Result<()> {
-        apt_install(&["git"])
+        apt_install(&[PACKAGE])
     }
 }
 
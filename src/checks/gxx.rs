@@ -14,9 +14,10 @@ fn fix_instructions(_cfg: &Config) -> String {
 
 pub mod apt {
     use super::*;
+    pub const PACKAGE: &str = "g++";
     pub fn check() -> Check {
         Check {
-            label: "g++",
+            label: PACKAGE,
             detect,
             fix_instructions: Some(fix_instructions),
             autofix: Some(Autofix {
@@ -26,7 +27,7 @@ pub mod apt {
         }
     }
     fn autofix(_cfg: &Config) -> Result<()> {
-        apt_install(&["g++"])
+        apt_install(&[PACKAGE])
     }
 }
 
@@ -9,9 +9,11 @@ fn fix_instructions(_cfg: &Config) -> String {
 
 pub mod apt {
     use super::*;
+    pub const PACKAGE: &str = "libturbojpeg0-dev";
+    pub const RUNTIME: &str = "libturbojpeg0";
     pub fn check() -> Check {
         Check {
-            label: "libturbojpeg0-dev",
+            label: PACKAGE,
             detect,
             fix_instructions: Some(fix_instructions),
             autofix: Some(Autofix {
@@ -21,10 +23,10 @@ pub mod apt {
         }
     }
     fn detect(_cfg: &Config) -> bool {
-        is_apt_installed("libturbojpeg0-dev")
+        is_apt_installed(PACKAGE)
     }
     fn autofix(_cfg: &Config) -> Result<()> {
-        apt_install(&["libturbojpeg0-dev"])
+        apt_install(&[PACKAGE])
     }
 }
 
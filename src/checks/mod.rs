@@ -4,6 +4,7 @@ use std::process::Command;
 use crate::config::Config;
 
 pub mod brew;
+pub mod cargo;
 pub mod curl;
 pub mod xcode_clt;
 pub mod git;
@@ -17,6 +18,11 @@ pub mod pre_commit_tool;
 pub mod python_dev;
 pub mod uv;
 
+// Each `apt` submodule exposes the Debian package it installs as `PACKAGE`,
+// so the Dockerfile `dimos new-app` generates installs exactly what
+// `dimos init` installs on the host. `-dev` packages also expose `RUNTIME`,
+// the shared library alone, for images that only run the built code.
+
 // ── core types ────────────────────────────────────────────────────────────────
 
 pub struct Autofix {
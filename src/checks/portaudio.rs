@@ -9,9 +9,11 @@ fn fix_instructions(_cfg: &Config) -> String {
 
 pub mod apt {
     use super::*;
+    pub const PACKAGE: &str = "portaudio19-dev";
+    pub const RUNTIME: &str = "libportaudio2";
     pub fn check() -> Check {
         Check {
-            label: "portaudio19-dev",
+            label: PACKAGE,
             detect,
             fix_instructions: Some(fix_instructions),
             autofix: Some(Autofix {
@@ -21,10 +23,10 @@ pub mod apt {
         }
     }
     fn detect(_cfg: &Config) -> bool {
-        is_apt_installed("portaudio19-dev")
+        is_apt_installed(PACKAGE)
     }
     fn autofix(_cfg: &Config) -> Result<()> {
-        apt_install(&["portaudio19-dev"])
+        apt_install(&[PACKAGE])
     }
 }
 
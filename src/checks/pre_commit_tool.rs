@@ -15,9 +15,10 @@ fn fix_instructions(_cfg: &Config) -> String {
 
 pub mod apt {
     use super::*;
+    pub const PACKAGE: &str = "pre-commit";
     pub fn check() -> Check {
         Check {
-            label: "pre-commit",
+            label: PACKAGE,
             detect,
             fix_instructions: Some(fix_instructions),
             autofix: Some(Autofix {
@@ -27,7 +28,7 @@ pub mod apt {
         }
     }
     fn autofix(_cfg: &Config) -> Result<()> {
-        apt_install(&["pre-commit"])
+        apt_install(&[PACKAGE])
     }
 }
 
@@ -67,6 +68,8 @@ pub mod nix {
     }
 }
 
+// Not wired into any install sequence yet.
+#[allow(dead_code)]
 pub mod pip {
     use super::*;
     pub fn check() -> Check {
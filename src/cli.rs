@@ -1,4 +1,10 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+
+use crate::{
+    bundle::{OnConflict, Section},
+    subcommands::new_app::{self, ci::Ci, style::Indent},
+};
 
 #[derive(Parser)]
 #[command(
@@ -17,10 +23,157 @@ pub enum Commands {
     /// Set up your development environment (nix, git, uv, git-lfs)
     Init,
 
-    /// Answer personality questions stored in your config
-    Survey,
+    /// Answer personality questions stored in your config (new or changed ones only)
+    Survey {
+        /// Re-ask only these questions, e.g. `--only personality.editor`
+        #[arg(long, value_delimiter = ',')]
+        only: Vec<String>,
+
+        /// Re-ask every question, not just new or changed ones
+        #[arg(long, conflicts_with = "only")]
+        all: bool,
+
+        /// YAML file of answers keyed by question address
+        #[arg(long, value_name = "FILE")]
+        answers: Option<PathBuf>,
+
+        /// Supply one answer, e.g. `--set personality.editor=neovim`
+        #[arg(long = "set", value_name = "ADDRESS=VALUE")]
+        set: Vec<String>,
+    },
 
     /// Scaffold a new Python application
     #[command(name = "new-app")]
-    NewApp,
+    NewApp(NewAppArgs),
+
+    /// Inspect and maintain the dimos config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Salvage a corrupt config, keeping every field that still parses
+    Repair,
+
+    /// Write your answers as a portable YAML bundle (stdout by default)
+    Export {
+        /// Export only one section of the config
+        #[arg(long, value_enum)]
+        only: Option<Section>,
+
+        /// Write the bundle to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Merge a bundle produced by `dimos config export` into your config
+    Import {
+        /// Bundle file to import
+        file: PathBuf,
+
+        /// How to resolve keys whose local value differs from the bundle
+        #[arg(long, value_enum, default_value_t = OnConflict::Ask)]
+        on_conflict: OnConflict,
+
+        /// Apply without the final confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// Every prompt has a flag; omitted values are asked for in a terminal.
+#[derive(Args)]
+pub struct NewAppArgs {
+    /// Project name (letters, digits, hyphens, underscores)
+    #[arg(long, value_parser = new_app::parse_name)]
+    pub name: Option<String>,
+
+    /// Short description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Python version
+    #[arg(long, value_parser = new_app::choices(new_app::PYTHON_VERSIONS))]
+    pub python: Option<String>,
+
+    /// Project type
+    #[arg(long = "type", value_parser = new_app::choices(new_app::PROJECT_TYPES))]
+    pub project_type: Option<String>,
+
+    /// Author name [default: git config user.name]
+    #[arg(long)]
+    pub author: Option<String>,
+
+    /// Source repository URL, written to the project URLs in pyproject.toml
+    #[arg(long)]
+    pub repository: Option<String>,
+
+    /// License
+    #[arg(long, value_parser = new_app::choices(new_app::LICENSES))]
+    pub license: Option<String>,
+
+    /// Directory to create the project in [default: <projects root>/<name>]
+    #[arg(long)]
+    pub dir: Option<PathBuf>,
+
+    /// Project-wide indentation, overriding your survey answer (for shared repos)
+    #[arg(long, value_enum)]
+    pub indent: Option<Indent>,
+
+    /// Project template: a built-in name, a name under ~/.dimos/templates, a
+    /// template directory, or git+<url>[#ref] [default: default]
+    #[arg(long, value_name = "NAME|PATH|URL")]
+    pub template: Option<String>,
+
+    /// Answer one of the template's own prompts (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE")]
+    pub vars: Vec<String>,
+
+    /// CI pipeline to generate
+    #[arg(long, value_enum, default_value_t = Ci::None)]
+    pub ci: Ci,
+
+    /// Generate a Dockerfile, .dockerignore and a devcontainer
+    #[arg(long)]
+    pub docker: bool,
+
+    /// Generate a nix flake dev shell and .envrc [default: on after a nix-based `dimos init`]
+    #[arg(long, conflicts_with = "no_nix")]
+    pub nix: bool,
+
+    /// Don't generate a nix flake, even after a nix-based `dimos init`
+    #[arg(long)]
+    pub no_nix: bool,
+
+    /// Skip the ruff/pytest/mypy setup and the tests/ directory
+    #[arg(long)]
+    pub no_dev_tools: bool,
+
+    /// Don't create a git repository (or its initial commit)
+    #[arg(long)]
+    pub no_git: bool,
+
+    /// If the directory is not empty, add only the files that are missing
+    #[arg(long, conflicts_with = "force")]
+    pub merge: bool,
+
+    /// If the directory is not empty, overwrite the files dimos generates
+    #[arg(long)]
+    pub force: bool,
+
+    /// Keep a partially created project if scaffolding fails, instead of
+    /// removing it
+    #[arg(long)]
+    pub keep_on_failure: bool,
+
+    /// Don't open a shell in the new project
+    #[arg(long)]
+    pub no_shell: bool,
+
+    /// Use defaults for anything not given as a flag and skip the confirmation
+    #[arg(short, long)]
+    pub yes: bool,
 }
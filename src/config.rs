@@ -1,8 +1,17 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs, io::IsTerminal, path::PathBuf};
+use serde_yaml::{Mapping, Value};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+};
 
-use crate::questions::QuestionKey;
+use crate::{
+    lock::FileLock,
+    questions::{Question, QuestionKey},
+};
 
 // ── data model ────────────────────────────────────────────────────────────────
 
@@ -13,87 +22,136 @@ pub struct Config {
     /// Flat key→value store for all question answers, keyed by QuestionKey::ADDRESS.
     #[serde(default)]
     pub answers: HashMap<String, String>,
+    /// Registry revision of the question each answer was given to, keyed by
+    /// address. Lets `dimos survey` re-ask only new or changed questions.
+    #[serde(default)]
+    pub revisions: HashMap<String, u32>,
+    /// Install sequence the last `dimos init` ran, e.g. `linux_nix`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub install_sequence: Option<String>,
+}
+
+impl Config {
+    /// Read a question answer by its compile-time key.
+    pub fn get<Q: QuestionKey>(&self) -> Option<&str> {
+        self.answers.get(Q::ADDRESS).map(String::as_str)
+    }
 }
 
 // ── manager ───────────────────────────────────────────────────────────────────
 
+/// Owns the config for one load → modify → save cycle.
+///
+/// An exclusive advisory lock on `config.yaml.lock` is held from load until
+/// the manager is dropped, so two `dimos` processes can never interleave their
+/// read-modify-write cycles and silently drop each other's answers.
 pub struct ConfigManager {
     path: PathBuf,
     pub config: Config,
+    _lock: FileLock,
 }
 
 impl ConfigManager {
     /// Load the config file, recovering gracefully if it is corrupt.
     pub fn load_or_recover() -> Result<Self> {
-        let path = config_path();
-        if !path.exists() {
+        let (path, lock) = lock_config()?;
+        let Some(text) = read_config(&path)? else {
             return Ok(Self {
                 path,
                 config: Config::default(),
+                _lock: lock,
             });
-        }
-
-        let text = fs::read_to_string(&path)
-            .with_context(|| format!("cannot read {}", path.display()))?;
+        };
 
         match serde_yaml::from_str::<Config>(&text) {
-            Ok(config) => Ok(Self { path, config }),
-            Err(e) => Self::recover(path, &text, e),
+            Ok(config) => Ok(Self {
+                path,
+                config,
+                _lock: lock,
+            }),
+            Err(e) => Self::recover(path, lock, &text, e),
         }
     }
 
-    fn recover(path: PathBuf, corrupt_text: &str, err: serde_yaml::Error) -> Result<Self> {
-        let backup = path.with_extension("yaml.corrupt.bak");
+    /// Load the config for `dimos config repair`, salvaging it if it does not
+    /// parse. The returned report is `None` when the file was already valid;
+    /// nothing is written until the caller saves.
+    pub fn load_for_repair() -> Result<(Self, Option<Repair>)> {
+        let (path, lock) = lock_config()?;
+        let text = read_config(&path)?.unwrap_or_default();
 
-        let reset = if std::io::stdin().is_terminal() {
+        let (config, report) = match serde_yaml::from_str::<Config>(&text) {
+            Ok(config) => (config, None),
+            Err(_) => {
+                let Salvage { config, dropped } = salvage(&text);
+                (
+                    config,
+                    Some(Repair {
+                        dropped,
+                        original: text,
+                    }),
+                )
+            }
+        };
+        Ok((
+            Self {
+                path,
+                config,
+                _lock: lock,
+            },
+            report,
+        ))
+    }
+
+    fn recover(
+        path: PathBuf,
+        lock: FileLock,
+        corrupt_text: &str,
+        err: serde_yaml::Error,
+    ) -> Result<Self> {
+        let backup = backup_path(&path);
+        let Salvage { config, dropped } = salvage(corrupt_text);
+
+        let accept = if std::io::stdin().is_terminal() {
+            cliclack::note("Dropped from config", format_dropped(&dropped))?;
             cliclack::confirm(format!(
-                "Config is corrupt ({err}).\nReset to defaults? (corrupt file → {})",
+                "Config is corrupt ({err}).\nContinue with the salvaged config? (original → {})",
                 backup.display()
             ))
             .initial_value(true)
             .interact()?
         } else {
-            // Non-interactive: auto-recover silently.
+            // Non-interactive: auto-recover, but say what was lost.
             true
         };
 
-        if !reset {
+        if !accept {
             anyhow::bail!(
-                "Config is corrupt. Fix or delete {} and re-run.",
+                "Config is corrupt. Fix it, run `dimos config repair`, or delete {} and re-run.",
                 path.display()
             );
         }
 
-        if let Some(parent) = backup.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(&backup, corrupt_text)
-            .with_context(|| format!("cannot write backup to {}", backup.display()))?;
+        write_backup(&path, corrupt_text)?;
 
         cliclack::log::warning(format!(
-            "Corrupt config backed up to {}. Starting fresh.",
-            backup.display()
+            "Corrupt config backed up to {}. Kept everything that still parses; dropped:\n{}",
+            backup.display(),
+            format_dropped(&dropped)
         ))?;
 
         Ok(Self {
             path,
-            config: Config::default(),
+            config,
+            _lock: lock,
         })
     }
 
+    /// Write the config atomically: a reader (or a crash) only ever sees the
+    /// old file or the new one, never a truncated mix of both.
     pub fn save(&self) -> Result<()> {
-        if let Some(dir) = self.path.parent() {
-            fs::create_dir_all(dir)
-                .with_context(|| format!("cannot create {}", dir.display()))?;
-        }
         let text = serde_yaml::to_string(&self.config)?;
-        fs::write(&self.path, text)
-            .with_context(|| format!("cannot write {}", self.path.display()))
-    }
-
-    /// Read a question answer by its compile-time key.
-    pub fn get<Q: QuestionKey>(&self) -> Option<&str> {
-        self.config.answers.get(Q::ADDRESS).map(String::as_str)
+        write_atomic(&self.path, text.as_bytes())
     }
 
     /// Store a question answer by its compile-time key.
@@ -101,21 +159,277 @@ impl ConfigManager {
         self.config.answers.insert(Q::ADDRESS.to_string(), value);
     }
 
+    /// Store a survey answer, recording which revision of the question it answers.
+    pub fn set_answer(&mut self, question: &Question, value: String) {
+        self.set_address(question.address, value);
+        self.config
+            .revisions
+            .insert(question.address.to_string(), question.revision);
+    }
+
+    /// Store an answer by its runtime address, for answers that arrive as
+    /// data (e.g. an imported bundle) rather than from a typed question.
+    pub fn set_address(&mut self, address: &str, value: String) {
+        self.config.answers.insert(address.to_string(), value);
+    }
+
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
 }
 
+// ── salvage ───────────────────────────────────────────────────────────────────
+
+/// A piece of a corrupt config that could not be kept.
+#[derive(Debug)]
+pub struct Dropped {
+    /// Dotted key (`answers.personality.editor`) or `line N` for syntax errors.
+    pub key: String,
+    pub reason: String,
+}
+
+pub struct Salvage {
+    pub config: Config,
+    pub dropped: Vec<Dropped>,
+}
+
+/// What `dimos config repair` found wrong with the file on disk.
+pub struct Repair {
+    pub dropped: Vec<Dropped>,
+    /// The unparseable text, to be backed up before the repaired config is saved.
+    pub original: String,
+}
+
+/// Recover as much of a config document as possible.
+///
+/// Lines that break YAML syntax are removed one at a time until the document
+/// parses as a generic value. Each top-level field is then validated on its
+/// own, and mapping fields (like `answers`) entry by entry, so one bad answer
+/// costs only that answer.
+pub fn salvage(text: &str) -> Salvage {
+    let (value, mut dropped) = parse_lenient(text);
+
+    let root = match value {
+        Value::Mapping(m) => m,
+        Value::Null => Mapping::new(),
+        other => {
+            dropped.push(Dropped {
+                key: "<document>".to_string(),
+                reason: format!("expected a mapping, found {}", kind(&other)),
+            });
+            Mapping::new()
+        }
+    };
+
+    let mut kept = Mapping::new();
+    for (key, value) in root {
+        let name = key_name(&key);
+        match validate_field(&key, &value) {
+            Ok(()) => {
+                kept.insert(key, value);
+            }
+            Err(e) => match value {
+                Value::Mapping(entries) => {
+                    let mut kept_entries = Mapping::new();
+                    for (sub_key, sub_value) in entries {
+                        let mut single = Mapping::new();
+                        single.insert(sub_key.clone(), sub_value.clone());
+                        match validate_field(&key, &Value::Mapping(single)) {
+                            Ok(()) => {
+                                kept_entries.insert(sub_key, sub_value);
+                            }
+                            Err(e) => dropped.push(Dropped {
+                                key: format!("{name}.{}", key_name(&sub_key)),
+                                reason: e.to_string(),
+                            }),
+                        }
+                    }
+                    kept.insert(key, Value::Mapping(kept_entries));
+                }
+                _ => dropped.push(Dropped {
+                    key: name,
+                    reason: e.to_string(),
+                }),
+            },
+        }
+    }
+
+    // Every kept field validated on its own, so the whole must too.
+    let config = serde_yaml::from_value(Value::Mapping(kept)).unwrap_or_default();
+    Salvage { config, dropped }
+}
+
+/// Check that `{key: value}` alone deserializes as a `Config`.
+fn validate_field(key: &Value, value: &Value) -> Result<(), serde_yaml::Error> {
+    let mut single = Mapping::new();
+    single.insert(key.clone(), value.clone());
+    serde_yaml::from_value::<Config>(Value::Mapping(single)).map(|_| ())
+}
+
+/// Parse `text` as a generic YAML value, dropping lines that break the syntax.
+fn parse_lenient(text: &str) -> (Value, Vec<Dropped>) {
+    // (original 1-based line number, line text)
+    let mut lines: Vec<(usize, &str)> = text.lines().enumerate().map(|(i, l)| (i + 1, l)).collect();
+    let mut dropped = Vec::new();
+
+    loop {
+        let doc = lines.iter().map(|(_, l)| *l).collect::<Vec<_>>().join("\n");
+        let err = match serde_yaml::from_str::<Value>(&doc) {
+            Ok(value) => return (value, dropped),
+            Err(e) => e,
+        };
+        if lines.is_empty() {
+            dropped.push(Dropped {
+                key: "<document>".to_string(),
+                reason: err.to_string(),
+            });
+            return (Value::Null, dropped);
+        }
+        let idx = blamed_line(&err)
+            .map(|line| line.saturating_sub(1))
+            .unwrap_or(lines.len() - 1)
+            .min(lines.len() - 1);
+        let (line_no, _) = lines.remove(idx);
+        dropped.push(Dropped {
+            key: format!("line {line_no}"),
+            reason: format!("YAML syntax error: {err}"),
+        });
+    }
+}
+
+/// The 1-based line to blame for a syntax error.
+///
+/// libyaml reports where it gave up ("unexpected end of stream at line 6")
+/// and, for unterminated constructs, where the construct began ("while
+/// scanning a quoted scalar at line 5"). The earliest line mentioned is the
+/// one that actually needs to go.
+fn blamed_line(err: &serde_yaml::Error) -> Option<usize> {
+    let message = err.to_string();
+    message
+        .split("line ")
+        .skip(1)
+        .filter_map(|rest| {
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .min()
+        .or_else(|| err.location().map(|loc| loc.line()))
+}
+
+fn key_name(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| kind(other).to_string()),
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Sequence(_) => "a sequence",
+        Value::Mapping(_) => "a mapping",
+        Value::Tagged(_) => "a tagged value",
+    }
+}
+
+pub fn format_dropped(dropped: &[Dropped]) -> String {
+    if dropped.is_empty() {
+        return "nothing".to_string();
+    }
+    dropped
+        .iter()
+        .map(|d| format!("• {} — {}", d.key, d.reason))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // ── helpers ───────────────────────────────────────────────────────────────────
 
-pub fn config_path() -> PathBuf {
+/// `~/.dimos`, home of the config file and dimos' lock files.
+pub fn dimos_dir() -> PathBuf {
     dirs::home_dir()
         .expect("cannot determine home directory")
         .join(".dimos")
-        .join("config.yaml")
+}
+
+pub fn config_path() -> PathBuf {
+    dimos_dir().join("config.yaml")
+}
+
+fn lock_config() -> Result<(PathBuf, FileLock)> {
+    let path = config_path();
+    let lock = FileLock::acquire(&path.with_extension("yaml.lock"))?;
+    Ok((path, lock))
+}
+
+/// The config file's text, or `None` if it does not exist yet.
+fn read_config(path: &Path) -> Result<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read_to_string(path)
+        .map(Some)
+        .with_context(|| format!("cannot read {}", path.display()))
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension("yaml.corrupt.bak")
+}
+
+/// Keep a copy of a corrupt config next to it before it gets overwritten.
+pub fn write_backup(path: &Path, corrupt_text: &str) -> Result<PathBuf> {
+    let backup = backup_path(path);
+    write_atomic(&backup, corrupt_text.as_bytes())
+        .with_context(|| format!("cannot write backup to {}", backup.display()))?;
+    Ok(backup)
+}
+
+/// Replace `path` with `contents` via a sibling temp file and a rename.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = dir.join(format!(".{file_name}.{}.tmp", std::process::id()));
+
+    let result = (|| -> Result<()> {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result.with_context(|| format!("cannot write {}", path.display()))
 }
 
 /// Convenience loader for subcommands that only need a read-only snapshot.
 pub fn load() -> Result<Config> {
     Ok(ConfigManager::load_or_recover()?.config)
 }
+
+/// A read-only snapshot that fails on a corrupt config instead of offering
+/// recovery, for commands whose stdout is data rather than a conversation.
+pub fn load_strict() -> Result<Config> {
+    let (path, _lock) = lock_config()?;
+    let Some(text) = read_config(&path)? else {
+        return Ok(Config::default());
+    };
+    serde_yaml::from_str(&text).with_context(|| {
+        format!(
+            "{} is corrupt — run `dimos config repair` first",
+            path.display()
+        )
+    })
+}
@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use std::{
+    fs::{self, File, OpenOptions, TryLockError},
+    io::{Seek, Write},
+    path::{Path, PathBuf},
+};
+
+/// An advisory, exclusive lock on a file; released when dropped.
+///
+/// The lock file itself is never deleted — removing it while another process
+/// is waiting on it would let two processes believe they hold the lock.
+pub struct FileLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Block until the lock is acquired, telling the user if we have to wait.
+    pub fn acquire(path: &Path) -> Result<Self> {
+        let file = open(path)?;
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                cliclack::log::info(format!(
+                    "Waiting for another dimos process to release {}…",
+                    path.display()
+                ))?;
+                file.lock()
+                    .with_context(|| format!("cannot lock {}", path.display()))?;
+            }
+            Err(TryLockError::Error(e)) => {
+                return Err(e).with_context(|| format!("cannot lock {}", path.display()));
+            }
+        }
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Acquire the lock without waiting; `None` if another process holds it.
+    pub fn try_acquire(path: &Path) -> Result<Option<Self>> {
+        let file = open(path)?;
+        match file.try_lock() {
+            Ok(()) => Ok(Some(Self {
+                file,
+                path: path.to_path_buf(),
+            })),
+            Err(TryLockError::WouldBlock) => Ok(None),
+            Err(TryLockError::Error(e)) => {
+                Err(e).with_context(|| format!("cannot lock {}", path.display()))
+            }
+        }
+    }
+
+    /// Record who holds the lock (pid, what it is doing) for diagnostics.
+    pub fn write_owner(&mut self, what: &str) -> Result<()> {
+        self.file.set_len(0)?;
+        self.file.rewind()?;
+        writeln!(self.file, "{} {what}", std::process::id())
+            .with_context(|| format!("cannot write {}", self.path.display()))
+    }
+}
+
+/// Read the owner line written by [`FileLock::write_owner`], if any.
+pub fn read_owner(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn open(path: &Path) -> Result<File> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(path)
+        .with_context(|| format!("cannot open {}", path.display()))
+}
@@ -1,10 +1,12 @@
 use anyhow::Result;
 use clap::Parser;
 
+mod bundle;
 mod checks;
 mod cli;
 mod config;
 mod install_sequences;
+mod lock;
 mod questions;
 mod subcommands;
 mod ui;
@@ -13,7 +15,23 @@ fn main() -> Result<()> {
     let args = cli::Cli::parse();
     match args.command {
         cli::Commands::Init => subcommands::init::run(),
-        cli::Commands::Survey => subcommands::survey::run(),
-        cli::Commands::NewApp => subcommands::new_app::run(),
+        cli::Commands::Survey {
+            only,
+            all,
+            answers,
+            set,
+        } => subcommands::survey::run(&only, all, answers.as_deref(), &set),
+        cli::Commands::NewApp(args) => subcommands::new_app::run(&args),
+        cli::Commands::Config { action } => match action {
+            cli::ConfigAction::Repair => subcommands::config::repair(),
+            cli::ConfigAction::Export { only, output } => {
+                subcommands::config::export(only, output.as_deref())
+            }
+            cli::ConfigAction::Import {
+                file,
+                on_conflict,
+                yes,
+            } => subcommands::config::import(&file, on_conflict, yes),
+        },
     }
 }
@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use crate::config::Config;
+
 /// Marker trait for a config field address.
 ///
 /// Each impl lives on a unique zero-size type, giving compile-time uniqueness:
@@ -10,6 +14,248 @@ pub trait QuestionKey {
     const ADDRESS: &'static str;
 }
 
+// ── registry ──────────────────────────────────────────────────────────────────
+
+/// How a question is asked, and what shape its stored answer has.
+///
+/// Answers are always stored as strings: multi-select answers are
+/// comma-separated option values, confirms are `"true"`/`"false"`.
+pub enum Kind {
+    Select,
+    // No question offers several choices yet.
+    #[allow(dead_code)]
+    MultiSelect,
+    Text { placeholder: &'static str },
+    Confirm,
+    // No question asks for a number yet.
+    #[allow(dead_code)]
+    Number { min: i64, max: i64 },
+}
+
+/// Custom answer check; the error is shown to the user as-is.
+pub type Validator = fn(&str) -> Result<(), String>;
+
+pub struct Choice {
+    pub value: &'static str,
+    pub label: &'static str,
+    pub hint: &'static str,
+}
+
+/// Everything needed to ask, validate and store one question.
+pub struct Question {
+    /// Config address, taken from the question's `QuestionKey` impl.
+    pub address: &'static str,
+    pub prompt: &'static str,
+    pub kind: Kind,
+    /// Allowed values for `Select` / `MultiSelect`; empty otherwise.
+    pub options: &'static [Choice],
+    /// Pre-selected answer when the user has not answered before.
+    pub default: Option<&'static str>,
+    /// Extra validation on top of what `kind` and `options` already enforce.
+    pub validate: Option<Validator>,
+    /// Bump whenever the prompt's meaning or options change, so users who
+    /// answered an older revision get asked again on their next survey.
+    pub revision: u32,
+    /// Only ask when an earlier answer matches.
+    pub show_if: Option<Condition>,
+}
+
+/// "Ask this only if `address` was answered with one of `any_of`."
+pub struct Condition {
+    pub address: &'static str,
+    pub any_of: &'static [&'static str],
+}
+
+impl Condition {
+    pub fn holds(&self, answers: &HashMap<String, String>) -> bool {
+        answers
+            .get(self.address)
+            .is_some_and(|a| self.any_of.contains(&a.as_str()))
+    }
+}
+
+impl Question {
+    /// Check a stored/supplied answer against this question's kind and options.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let allowed = || {
+            self.options
+                .iter()
+                .map(|c| c.value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self.kind {
+            Kind::Select => {
+                if !self.options.iter().any(|c| c.value == value) {
+                    return Err(format!("`{value}` is not one of: {}", allowed()));
+                }
+            }
+            Kind::MultiSelect => {
+                for item in split_multi(value) {
+                    if !self.options.iter().any(|c| c.value == item) {
+                        return Err(format!("`{item}` is not one of: {}", allowed()));
+                    }
+                }
+            }
+            Kind::Text { .. } => {
+                if value.trim().is_empty() {
+                    return Err("answer cannot be empty".to_string());
+                }
+            }
+            Kind::Confirm => {
+                if value != "true" && value != "false" {
+                    return Err(format!("`{value}` is not `true` or `false`"));
+                }
+            }
+            Kind::Number { min, max } => match value.parse::<i64>() {
+                Ok(n) if (min..=max).contains(&n) => {}
+                _ => return Err(format!("`{value}` is not a number between {min} and {max}")),
+            },
+        }
+        match self.validate {
+            Some(validate) => validate(value),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether this question should be asked, given the answers so far.
+    pub fn is_visible(&self, answers: &HashMap<String, String>) -> bool {
+        self.show_if.as_ref().is_none_or(|c| c.holds(answers))
+    }
+
+    /// Whether the question is visible and its stored answer is missing,
+    /// invalid, or from an older revision of the question. Answers recorded
+    /// before revisions existed count as revision 1.
+    pub fn needs_answer(&self, config: &Config) -> bool {
+        if !self.is_visible(&config.answers) {
+            return false;
+        }
+        match config.answers.get(self.address) {
+            None => true,
+            Some(answer) => {
+                self.validate(answer).is_err()
+                    || config.revisions.get(self.address).copied().unwrap_or(1) < self.revision
+            }
+        }
+    }
+
+    /// The option whose value equals `value`, with a `'static` lifetime.
+    pub fn option(&self, value: &str) -> Option<&'static Choice> {
+        self.options.iter().find(|c| c.value == value)
+    }
+}
+
+/// Split a stored multi-select answer into its option values.
+pub fn split_multi(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+pub fn find(address: &str) -> Option<&'static Question> {
+    QUESTIONS.iter().find(|q| q.address == address)
+}
+
+/// Every survey question, in the order `dimos survey` asks them.
+pub static QUESTIONS: &[Question] = &[
+    Question {
+        address: PersonalityEditor::ADDRESS,
+        prompt: "What's your editor of choice?",
+        kind: Kind::Select,
+        options: &[
+            Choice { value: "neovim", label: "Neovim", hint: "" },
+            Choice { value: "emacs", label: "Emacs", hint: "" },
+            Choice { value: "vscode", label: "VS Code", hint: "" },
+            Choice { value: "zed", label: "Zed", hint: "" },
+            Choice { value: "other", label: "Other / CLI", hint: "" },
+        ],
+        default: None,
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+    Question {
+        address: PersonalityNeovimPluginManager::ADDRESS,
+        prompt: "Which Neovim plugin manager do you use?",
+        kind: Kind::Select,
+        options: &[
+            Choice { value: "lazy", label: "lazy.nvim", hint: "" },
+            Choice { value: "packer", label: "packer.nvim", hint: "" },
+            Choice { value: "vim_plug", label: "vim-plug", hint: "" },
+            Choice { value: "none", label: "None", hint: "native packages" },
+        ],
+        default: Some("lazy"),
+        validate: None,
+        revision: 1,
+        show_if: Some(Condition {
+            address: PersonalityEditor::ADDRESS,
+            any_of: &["neovim"],
+        }),
+    },
+    Question {
+        address: PersonalityIndentation::ADDRESS,
+        prompt: "Tabs or spaces?",
+        kind: Kind::Select,
+        options: &[
+            Choice { value: "spaces", label: "Spaces", hint: "" },
+            Choice { value: "tabs", label: "Tabs", hint: "heresy" },
+        ],
+        default: None,
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+    Question {
+        address: PersonalityLanguage::ADDRESS,
+        prompt: "Primary programming language?",
+        kind: Kind::Text {
+            placeholder: "e.g. Python, Rust, TypeScript",
+        },
+        options: &[],
+        default: None,
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+    Question {
+        address: PersonalitySchedule::ADDRESS,
+        prompt: "When do you do your best work?",
+        kind: Kind::Select,
+        options: &[
+            Choice { value: "morning", label: "Early bird", hint: "up before the coffee" },
+            Choice { value: "night", label: "Night owl", hint: "when everyone else is asleep" },
+            Choice { value: "whenever", label: "Whenever the flow hits", hint: "chaos schedule" },
+        ],
+        default: None,
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+    Question {
+        address: PersonalityDebugStyle::ADDRESS,
+        prompt: "How do you debug?",
+        kind: Kind::Select,
+        options: &[
+            Choice { value: "prints", label: "Print statements", hint: "the classic" },
+            Choice { value: "debugger", label: "Proper debugger", hint: "breakpoints and watches" },
+            Choice { value: "rubber_duck", label: "Rubber duck", hint: "talking it through" },
+            Choice { value: "rewrite", label: "Rewrite until it works", hint: "burn it down" },
+        ],
+        default: None,
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+    Question {
+        address: NewAppRememberLast::ADDRESS,
+        prompt: "Remember your last `dimos new-app` choices as defaults?",
+        kind: Kind::Confirm,
+        options: &[],
+        default: Some("true"),
+        validate: None,
+        revision: 1,
+        show_if: None,
+    },
+];
+
 // ── personality ───────────────────────────────────────────────────────────────
 
 pub struct PersonalityEditor;
@@ -17,6 +263,11 @@ impl QuestionKey for PersonalityEditor {
     const ADDRESS: &'static str = "personality.editor";
 }
 
+pub struct PersonalityNeovimPluginManager;
+impl QuestionKey for PersonalityNeovimPluginManager {
+    const ADDRESS: &'static str = "personality.neovim_plugin_manager";
+}
+
 pub struct PersonalityIndentation;
 impl QuestionKey for PersonalityIndentation {
     const ADDRESS: &'static str = "personality.indentation";
@@ -36,3 +287,32 @@ pub struct PersonalityDebugStyle;
 impl QuestionKey for PersonalityDebugStyle {
     const ADDRESS: &'static str = "personality.debug_style";
 }
+
+// ── new-app defaults ──────────────────────────────────────────────────────────
+
+pub struct NewAppDefaultPython;
+impl QuestionKey for NewAppDefaultPython {
+    const ADDRESS: &'static str = "new_app.default_python";
+}
+
+pub struct NewAppDefaultLicense;
+impl QuestionKey for NewAppDefaultLicense {
+    const ADDRESS: &'static str = "new_app.default_license";
+}
+
+pub struct NewAppDefaultType;
+impl QuestionKey for NewAppDefaultType {
+    const ADDRESS: &'static str = "new_app.default_type";
+}
+
+/// Directory new projects are created in, e.g. `/home/me/code`.
+pub struct NewAppProjectsRoot;
+impl QuestionKey for NewAppProjectsRoot {
+    const ADDRESS: &'static str = "new_app.projects_root";
+}
+
+/// `"true"` to overwrite the defaults above with each run's choices.
+pub struct NewAppRememberLast;
+impl QuestionKey for NewAppRememberLast {
+    const ADDRESS: &'static str = "new_app.remember_last";
+}
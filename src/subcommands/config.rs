@@ -0,0 +1,149 @@
+use anyhow::{bail, Result};
+use std::{io::IsTerminal, path::Path};
+
+use crate::{
+    bundle::{self, Bundle, Change, OnConflict, Section},
+    config::{self, ConfigManager},
+    questions,
+    ui,
+};
+
+pub fn repair() -> Result<()> {
+    ui::header(" Dimos — Config Repair");
+
+    let (mgr, report) = ConfigManager::load_for_repair()?;
+    let Some(report) = report else {
+        ui::outro(format!("{} is valid — nothing to repair.", mgr.path().display()));
+        return Ok(());
+    };
+
+    cliclack::note("Dropped from config", config::format_dropped(&report.dropped))?;
+
+    if std::io::stdin().is_terminal() {
+        let yes = cliclack::confirm("Write the repaired config?")
+            .initial_value(true)
+            .interact()?;
+        if !yes {
+            ui::outro_cancel("Left the config untouched.");
+            return Ok(());
+        }
+    }
+
+    let backup = config::write_backup(mgr.path(), &report.original)?;
+    mgr.save()?;
+    cliclack::log::success(format!("Original backed up to {}", backup.display()))?;
+
+    ui::outro(format!("Repaired → {}", mgr.path().display()));
+    Ok(())
+}
+
+pub fn export(only: Option<Section>, output: Option<&Path>) -> Result<()> {
+    // Never prompt: stdout may be carrying the bundle.
+    let cfg = config::load_strict()?;
+    let text = serde_yaml::to_string(&Bundle::export(&cfg, only))?;
+
+    match output {
+        // Plain YAML on stdout so the bundle can be piped or redirected.
+        None => print!("{text}"),
+        Some(path) => {
+            config::write_atomic(path, text.as_bytes())?;
+            cliclack::log::success(format!("Bundle written → {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn import(file: &Path, on_conflict: OnConflict, yes: bool) -> Result<()> {
+    ui::header(" Dimos — Config Import");
+
+    let incoming = Bundle::read(file)?;
+    // Unknown addresses may come from a newer dimos and are kept as-is;
+    // known ones must be answers this dimos would accept itself.
+    let invalid: Vec<String> = incoming
+        .answers
+        .iter()
+        .filter_map(|(key, value)| {
+            let question = questions::find(key)?;
+            question.validate(value).err().map(|e| format!("{key}: {e}"))
+        })
+        .collect();
+    if !invalid.is_empty() {
+        bail!(
+            "{} has invalid answers:\n{}",
+            file.display(),
+            invalid.join("\n")
+        );
+    }
+    // Changes are chosen against a snapshot; the config lock is only taken
+    // to apply them, so an open prompt blocks no other command.
+    let current = config::load()?;
+
+    let changes = bundle::diff(&current, &incoming);
+    if changes.is_empty() {
+        ui::outro("Your config already matches the bundle — nothing to import.");
+        return Ok(());
+    }
+
+    cliclack::note("Incoming changes", bundle::format_diff(&changes))?;
+
+    let interactive = std::io::stdin().is_terminal();
+    let has_conflicts = changes.iter().any(|c| matches!(c, Change::Conflict { .. }));
+    if has_conflicts && on_conflict == OnConflict::Ask && !interactive {
+        bail!("The bundle conflicts with local answers — re-run with --on-conflict keep|take.");
+    }
+
+    let mut accepted = Vec::new();
+    for change in changes {
+        let take = match &change {
+            Change::Add { .. } => true,
+            Change::Conflict { key, ours, theirs } => match on_conflict {
+                OnConflict::Keep => false,
+                OnConflict::Take => true,
+                OnConflict::Ask => cliclack::select(format!("Conflict on {key}"))
+                    .item(false, format!("Keep mine ({ours})"), "")
+                    .item(true, format!("Take bundle ({theirs})"), "")
+                    .interact()?,
+            },
+        };
+        if take {
+            accepted.push(change);
+        }
+    }
+
+    if accepted.is_empty() {
+        ui::outro("Kept every local answer — nothing to import.");
+        return Ok(());
+    }
+
+    if interactive && !yes {
+        let apply = cliclack::confirm(format!("Apply {} change(s)?", accepted.len()))
+            .initial_value(true)
+            .interact()?;
+        if !apply {
+            ui::outro_cancel("Import cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut mgr = ConfigManager::load_or_recover()?;
+    for change in &accepted {
+        let value = match change {
+            Change::Add { value, .. } => value,
+            Change::Conflict { theirs, .. } => theirs,
+        };
+        // Known answers were validated against this dimos' questions, so they
+        // answer the current revision.
+        match questions::find(change.key()) {
+            Some(question) => mgr.set_answer(question, value.clone()),
+            None => mgr.set_address(change.key(), value.clone()),
+        }
+    }
+    mgr.save()?;
+
+    ui::outro(format!(
+        "Imported {} answer(s) → {}",
+        accepted.len(),
+        mgr.path().display()
+    ));
+    Ok(())
+}
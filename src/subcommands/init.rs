@@ -1,19 +1,38 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use which::which;
 
 use crate::{
-    config::ConfigManager,
+    config::{self, ConfigManager},
     install_sequences::{linux_apt, linux_nix, macos_brew, macos_nix},
+    lock::{self, FileLock},
     ui,
 };
 
 pub fn run() -> Result<()> {
+    // Held for the whole run so two inits never install packages concurrently.
+    let lock_path = config::dimos_dir().join("init.lock");
+    let Some(mut instance) = FileLock::try_acquire(&lock_path)? else {
+        let owner = lock::read_owner(&lock_path)
+            .map(|o| format!(" (pid {o})"))
+            .unwrap_or_default();
+        bail!(
+            "Another `dimos init` is already running{owner}. \
+             Wait for it to finish, then re-run."
+        );
+    };
+    instance.write_owner("dimos init")?;
+
     ui::header(" Dimos — Init");
 
-    let mut mgr = ConfigManager::load_or_recover()?;
-    mgr.config.init_completed = true;
-    mgr.save()?;
-    cliclack::log::success(format!("Config saved → {}", mgr.path().display()))?;
+    // Scope the manager so the config lock is released before the (long)
+    // install sequence runs — other dimos commands may read/write meanwhile.
+    let config = {
+        let mut mgr = ConfigManager::load_or_recover()?;
+        mgr.config.init_completed = true;
+        mgr.save()?;
+        cliclack::log::success(format!("Config saved → {}", mgr.path().display()))?;
+        mgr.config
+    };
 
     let is_linux = std::env::consts::OS == "linux";
     let is_macos = std::env::consts::OS == "macos";
@@ -36,15 +55,27 @@ pub fn run() -> Result<()> {
 
     let sequence_key: &str = select.interact()?;
 
-    match sequence_key {
-        "linux_apt" => linux_apt::sequence().run(&mgr.config)?,
-        "linux_nix" => linux_nix::sequence().run(&mgr.config)?,
-        "macos_brew" => macos_brew::sequence().run(&mgr.config)?,
-        "macos_nix" => macos_nix::sequence().run(&mgr.config)?,
+    let sequence = match sequence_key {
+        "linux_apt" => linux_apt::sequence(),
+        "linux_nix" => linux_nix::sequence(),
+        "macos_brew" => macos_brew::sequence(),
+        "macos_nix" => macos_nix::sequence(),
         _ => {
             ui::outro("Skipped. Run `dimos init` again to run an install sequence.");
+            return Ok(());
         }
-    }
+    };
+
+    instance.write_owner(&format!("dimos init {}", sequence.name))?;
+
+    sequence.run(&config)?;
+
+    // Recorded once the sequence succeeds, so later commands can match this
+    // environment, e.g. new-app adds a nix flake to projects after a
+    // nix-based init.
+    let mut mgr = ConfigManager::load_or_recover()?;
+    mgr.config.install_sequence = Some(sequence.name.to_string());
+    mgr.save()?;
 
     Ok(())
 }
@@ -0,0 +1,141 @@
+//! CI pipelines (`--ci`): `uv sync`, then ruff, mypy and pytest across
+//! every supported Python from the project's minimum up.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use std::fs;
+
+use super::{Project, PYTHON_VERSIONS};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Ci {
+    Github,
+    Gitlab,
+    #[default]
+    None,
+}
+
+/// Write the pipeline for `ci`; returns the path written, if any.
+pub fn write_files(project: &Project, ci: Ci) -> Result<Option<&'static str>> {
+    let (rel, text) = match ci {
+        Ci::Github => (".github/workflows/ci.yml", github(project)),
+        Ci::Gitlab => (".gitlab-ci.yml", gitlab(project)),
+        Ci::None => return Ok(None),
+    };
+    Ok(project.write(rel, &text)?.then_some(rel))
+}
+
+/// The project's Python and every newer one dimos offers, oldest first.
+fn matrix(project: &Project) -> Vec<&'static str> {
+    let minor = |v: &str| v.split('.').nth(1).and_then(|m| m.parse::<u32>().ok());
+    let min = minor(&project.python_version).unwrap_or(0);
+    let mut versions: Vec<&str> = PYTHON_VERSIONS
+        .iter()
+        .map(|(v, ..)| *v)
+        .filter(|v| minor(v).is_some_and(|m| m >= min))
+        .collect();
+    versions.sort_by_key(|v| minor(v));
+    versions
+}
+
+/// Whether `.gitattributes` routes any paths through git-lfs.
+fn uses_lfs(project: &Project) -> bool {
+    fs::read_to_string(project.dir.join(".gitattributes"))
+        .is_ok_and(|text| text.lines().any(|l| l.contains("filter=lfs")))
+}
+
+/// The check commands, via the dev group when it exists.
+fn checks(project: &Project) -> Vec<&'static str> {
+    if project.dev_tools {
+        vec![
+            "uv run ruff check .",
+            "uv run ruff format --check .",
+            "uv run mypy",
+            "uv run pytest",
+        ]
+    } else {
+        vec!["uvx ruff check .", "uvx ruff format --check ."]
+    }
+}
+
+fn quoted_list(items: &[&str]) -> String {
+    let quoted: Vec<String> = items.iter().map(|v| format!("\"{v}\"")).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+fn github(project: &Project) -> String {
+    let mut text = format!(
+        "\
+name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    strategy:
+      fail-fast: false
+      matrix:
+        python-version: {}
+    steps:
+      - uses: actions/checkout@v4
+",
+        quoted_list(&matrix(project))
+    );
+    if uses_lfs(project) {
+        text.push_str("        with:\n          lfs: true\n");
+    }
+    text.push_str(
+        "      - uses: astral-sh/setup-uv@v6\n        \
+         with:\n          \
+         python-version: ${{ matrix.python-version }}\n          \
+         enable-cache: true\n      \
+         - run: uv sync --locked\n",
+    );
+    for check in checks(project) {
+        text.push_str(&format!("      - run: {check}\n"));
+    }
+    text
+}
+
+fn gitlab(project: &Project) -> String {
+    let mut text = format!(
+        "\
+stages: [test]
+
+variables:
+  UV_CACHE_DIR: .uv-cache
+  UV_LINK_MODE: copy
+
+test:
+  stage: test
+  image: ghcr.io/astral-sh/uv:python$PYTHON_VERSION-bookworm-slim
+  parallel:
+    matrix:
+      - PYTHON_VERSION: {}
+  variables:
+    UV_PYTHON: $PYTHON_VERSION
+  cache:
+    key:
+      files: [uv.lock]
+    paths: [.uv-cache]
+",
+        quoted_list(&matrix(project))
+    );
+    if uses_lfs(project) {
+        text.push_str(
+            "  before_script:\n    \
+             - apt-get update && apt-get install -y --no-install-recommends git git-lfs\n    \
+             - git lfs install && git lfs pull\n",
+        );
+    }
+    text.push_str("  script:\n    - uv sync --locked\n");
+    for check in checks(project) {
+        text.push_str(&format!("    - {check}\n"));
+    }
+    text.push_str("    - uv cache prune --ci\n");
+    text
+}
@@ -0,0 +1,146 @@
+//! Container files (`--docker`): a multi-stage uv `Dockerfile`,
+//! `.dockerignore` and a devcontainer built from the Dockerfile's `dev`
+//! stage. System packages are the ones the `linux_apt` checks install, so
+//! a container matches a host set up by `dimos init`.
+
+use anyhow::Result;
+use serde_json::json;
+
+use super::{editor::write_json, Project};
+use crate::checks;
+
+/// Write the container files; returns the paths written.
+pub fn write_files(project: &Project) -> Result<Vec<&'static str>> {
+    let mut written = Vec::new();
+    if project.write("Dockerfile", &dockerfile(project))? {
+        written.push("Dockerfile");
+    }
+    if project.write(".dockerignore", DOCKERIGNORE)? {
+        written.push(".dockerignore");
+    }
+    let devcontainer = json!({
+        "name": project.name,
+        "build": {
+            "dockerfile": "../Dockerfile",
+            "context": "..",
+            "target": "dev"
+        },
+        "postCreateCommand": "git lfs install && uv sync",
+        "customizations": {
+            "vscode": {
+                "extensions": ["ms-python.python", "charliermarsh.ruff"],
+                "settings": {
+                    "python.defaultInterpreterPath": "${containerWorkspaceFolder}/.venv/bin/python"
+                }
+            }
+        }
+    });
+    if write_json(project, ".devcontainer/devcontainer.json", &devcontainer)? {
+        written.push(".devcontainer/devcontainer.json");
+    }
+    Ok(written)
+}
+
+fn dockerfile(project: &Project) -> String {
+    let python = &project.python_version;
+    // Shared libraries the project's native dependencies load at run time,
+    // and the headers they are built against.
+    let libraries = [
+        checks::portaudio::apt::RUNTIME,
+        checks::libturbojpeg::apt::RUNTIME,
+    ]
+    .join(" ");
+    let headers = [
+        checks::portaudio::apt::PACKAGE,
+        checks::libturbojpeg::apt::PACKAGE,
+    ]
+    .join(" ");
+    let compiler = checks::gxx::apt::PACKAGE;
+    let dev_tools = [
+        checks::git::apt::PACKAGE,
+        checks::git_lfs::apt::PACKAGE,
+        checks::curl::apt::PACKAGE,
+        checks::pre_commit_tool::apt::PACKAGE,
+    ]
+    .join(" ");
+    let cmd = match project.project_type.as_str() {
+        "lib" | "rust-ext" => format!(r#"["python", "-c", "import {}"]"#, project.module()),
+        // Console scripts from [project.scripts] are on PATH via the venv.
+        "cli" => format!(r#"["{}", "--help"]"#, project.name),
+        "service" => format!(r#"["{}"]"#, project.name),
+        _ => r#"["python", "main.py"]"#.to_string(),
+    };
+    // maturin compiles the extension; the runtime only needs the wheel.
+    let rust = if project.project_type == "rust-ext" {
+        "COPY --from=rust:1-slim-bookworm /usr/local/cargo /usr/local/cargo
+COPY --from=rust:1-slim-bookworm /usr/local/rustup /usr/local/rustup
+ENV RUSTUP_HOME=/usr/local/rustup \\
+    CARGO_HOME=/usr/local/cargo \\
+    PATH=/usr/local/cargo/bin:$PATH
+"
+    } else {
+        ""
+    };
+    // Services listen on all interfaces inside the container.
+    let service = if project.project_type == "service" {
+        "ENV HOST=0.0.0.0\nEXPOSE 8000\n"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"# syntax=docker/dockerfile:1
+
+# ── base: runtime libraries for what `dimos init` installs ──────────────────
+FROM python:{python}-slim-bookworm AS base
+RUN apt-get update \
+ && apt-get install -y --no-install-recommends {libraries} \
+ && rm -rf /var/lib/apt/lists/*
+COPY --from=ghcr.io/astral-sh/uv:latest /uv /uvx /bin/
+ENV UV_LINK_MODE=copy \
+    UV_PYTHON_DOWNLOADS=0
+
+# ── builder: dependencies first, so that layer is reused until uv.lock changes
+FROM base AS builder
+RUN apt-get update \
+ && apt-get install -y --no-install-recommends {compiler} {headers} \
+ && rm -rf /var/lib/apt/lists/*
+{rust}ENV UV_COMPILE_BYTECODE=1
+WORKDIR /app
+RUN --mount=type=cache,target=/root/.cache/uv \
+    --mount=type=bind,source=uv.lock,target=uv.lock \
+    --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+    uv sync --locked --no-install-project --no-dev
+COPY . /app
+RUN --mount=type=cache,target=/root/.cache/uv \
+    uv sync --locked --no-dev
+
+# ── dev: the devcontainer; the workspace is mounted, not copied ─────────────
+FROM base AS dev
+RUN apt-get update \
+ && apt-get install -y --no-install-recommends {compiler} {headers} {dev_tools} \
+ && rm -rf /var/lib/apt/lists/*
+{rust}
+# ── runtime ─────────────────────────────────────────────────────────────────
+FROM base AS runtime
+WORKDIR /app
+COPY --from=builder /app /app
+ENV PATH="/app/.venv/bin:$PATH"
+{service}CMD {cmd}
+"#
+    )
+}
+
+const DOCKERIGNORE: &str = "\
+.git
+.venv
+__pycache__/
+*.py[cod]
+.pytest_cache/
+.mypy_cache/
+.ruff_cache/
+.uv-cache/
+dist/
+build/
+target/
+";
@@ -0,0 +1,167 @@
+//! Debugging support generated from the `personality.debug_style` answer.
+//!
+//! - `debugger`: `debugpy` in the `dev` dependency group plus a launch
+//!   configuration for the user's editor.
+//! - `prints`: a `logsetup` module whose level is controlled by an env var.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{editor::write_json, Project};
+
+/// Write the files for `debug_style`; returns the paths written, relative to
+/// the project root.
+pub fn write_files(
+    project: &Project,
+    debug_style: &str,
+    editor: Option<&str>,
+) -> Result<Vec<String>> {
+    match debug_style {
+        "debugger" => debugger(project, editor),
+        "prints" => logging_module(project),
+        _ => Ok(vec![]),
+    }
+}
+
+fn debugger(project: &Project, editor: Option<&str>) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+    if let Some(mut pyproject) = project.pyproject()? {
+        pyproject.add_to_group("dev", &["debugpy"])?;
+        pyproject.save()?;
+        written.push("pyproject.toml (dev group)".to_string());
+    }
+
+    // nvim-dap reads .vscode/launch.json natively, so Neovim shares it.
+    let launch = match editor {
+        Some("vscode" | "neovim") => Some((".vscode/launch.json", vscode_launch(project))),
+        Some("zed") => Some((".zed/debug.json", zed_debug(project))),
+        _ => None,
+    };
+    if let Some((rel, value)) = launch {
+        if write_json(project, rel, &value)? {
+            written.push(rel.to_string());
+        }
+    }
+    Ok(written)
+}
+
+fn vscode_launch(project: &Project) -> Value {
+    let mut configurations = vec![json!({
+        "name": "Python: current file",
+        "type": "debugpy",
+        "request": "launch",
+        "program": "${file}",
+        "console": "integratedTerminal",
+        "python": "${workspaceFolder}/.venv/bin/python"
+    })];
+    if let Some(entry) = project.entry_module() {
+        let mut launch = json!({
+            "name": format!("Python: {}", project.name),
+            "type": "debugpy",
+            "request": "launch",
+            "console": "integratedTerminal",
+            "python": "${workspaceFolder}/.venv/bin/python"
+        });
+        if project.src_layout() {
+            launch["module"] = json!(entry);
+        } else {
+            launch["program"] = json!("${workspaceFolder}/main.py");
+        }
+        configurations.insert(0, launch);
+    }
+    json!({
+        "version": "0.2.0",
+        "configurations": configurations
+    })
+}
+
+fn zed_debug(project: &Project) -> Value {
+    let mut configurations = vec![json!({
+        "label": "Python: current file",
+        "adapter": "Debugpy",
+        "request": "launch",
+        "program": "$ZED_FILE",
+        "cwd": "$ZED_WORKTREE_ROOT"
+    })];
+    if let Some(entry) = project.entry_module() {
+        let mut launch = json!({
+            "label": format!("Python: {}", project.name),
+            "adapter": "Debugpy",
+            "request": "launch",
+            "cwd": "$ZED_WORKTREE_ROOT"
+        });
+        if project.src_layout() {
+            launch["module"] = json!(entry);
+        } else {
+            launch["program"] = json!("$ZED_WORKTREE_ROOT/main.py");
+        }
+        configurations.insert(0, launch);
+    }
+    Value::Array(configurations)
+}
+
+fn logging_module(project: &Project) -> Result<Vec<String>> {
+    let module = project.module();
+    // Packaged projects keep it inside the package; others are a flat main.py.
+    let rel = if project.src_layout() {
+        format!("src/{module}/logsetup.py")
+    } else {
+        "logsetup.py".to_string()
+    };
+    if !project.write(&rel, &logsetup_py(project))? {
+        return Ok(vec![]);
+    }
+    Ok(vec![rel])
+}
+
+fn logsetup_py(project: &Project) -> String {
+    let name = &project.name;
+    let module = project.module();
+    let env = format!("{}_LOG_LEVEL", module.to_uppercase());
+    let import = if project.src_layout() {
+        format!("from {module}.logsetup import setup_logging")
+    } else {
+        "from logsetup import setup_logging".to_string()
+    };
+    // Packaged apps run through their `[project.scripts]` entry point.
+    let run = match project.entry_module() {
+        Some(_) if project.src_layout() => format!("uv run {name}"),
+        Some(_) => "uv run main.py".to_string(),
+        None => format!(r#"uv run python -c "import {module}""#),
+    };
+    format!(
+        r#""""Logging setup for {name}.
+
+Call `setup_logging()` once at startup, then log instead of printing:
+
+    {import}
+
+    log = setup_logging()
+    log.debug("value=%r", value)
+
+The level comes from the `{env}` environment variable
+(DEBUG, INFO, WARNING, ERROR; default INFO), e.g.
+
+    {env}=DEBUG {run}
+"""
+
+import logging
+import os
+
+LEVEL_ENV_VAR = "{env}"
+
+
+def setup_logging() -> logging.Logger:
+    level_name = os.environ.get(LEVEL_ENV_VAR, "INFO").upper()
+    level = logging.getLevelName(level_name)
+    if not isinstance(level, int):
+        level = logging.INFO
+    logging.basicConfig(
+        level=level,
+        format="%(asctime)s %(levelname)-8s %(name)s: %(message)s",
+        datefmt="%H:%M:%S",
+    )
+    return logging.getLogger("{module}")
+"#
+    )
+}
@@ -0,0 +1,94 @@
+//! Editor project files generated from the `personality.editor` answer.
+//!
+//! Every editor is pointed at the uv virtualenv (`.venv`) so imports resolve
+//! and the right interpreter is used without any manual setup.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::Project;
+
+/// Write the files for `editor`; returns the paths written, relative to the
+/// project root. Editors without project-level settings get nothing.
+pub fn write_files(project: &Project, editor: &str) -> Result<Vec<&'static str>> {
+    let files: Vec<(&'static str, Value)> = match editor {
+        "vscode" => vec![
+            (".vscode/settings.json", vscode_settings()),
+            (".vscode/extensions.json", vscode_extensions()),
+        ],
+        "neovim" => vec![("pyrightconfig.json", pyright_config(project))],
+        "zed" => vec![(".zed/settings.json", zed_settings())],
+        _ => vec![],
+    };
+
+    let mut written = Vec::new();
+    for (rel, value) in &files {
+        if write_json(project, rel, value)? {
+            written.push(*rel);
+        }
+    }
+    Ok(written)
+}
+
+fn vscode_settings() -> Value {
+    json!({
+        "python.defaultInterpreterPath": "${workspaceFolder}/.venv/bin/python",
+        "python.terminal.activateEnvironment": true,
+        "[python]": {
+            "editor.defaultFormatter": "charliermarsh.ruff",
+            "editor.formatOnSave": true
+        }
+    })
+}
+
+fn vscode_extensions() -> Value {
+    json!({
+        "recommendations": [
+            "ms-python.python",
+            "ms-python.vscode-pylance",
+            "charliermarsh.ruff"
+        ]
+    })
+}
+
+/// Read by pyright/basedpyright, which Neovim's LSP setups (and ruff's
+/// editor integrations alongside them) use to find the environment.
+fn pyright_config(project: &Project) -> Value {
+    json!({
+        "venvPath": ".",
+        "venv": ".venv",
+        "pythonVersion": project.python_version,
+    })
+}
+
+fn zed_settings() -> Value {
+    json!({
+        "languages": {
+            "Python": {
+                "language_servers": ["pyright", "ruff"]
+            }
+        },
+        "lsp": {
+            "pyright": {
+                "settings": {
+                    "python": {
+                        "pythonPath": ".venv/bin/python"
+                    }
+                }
+            }
+        },
+        "terminal": {
+            "detect_venv": {
+                "on": {
+                    "directories": [".venv"],
+                    "activate_script": "default"
+                }
+            }
+        }
+    })
+}
+
+/// See [`Project::write`].
+pub(super) fn write_json(project: &Project, rel: &str, value: &Value) -> Result<bool> {
+    project.write(rel, &(serde_json::to_string_pretty(value)? + "\n"))
+}
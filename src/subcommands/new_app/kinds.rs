@@ -0,0 +1,270 @@
+//! Scaffold steps specific to a project type, beyond what `uv init`
+//! produces:
+//!
+//! - `cli`: an argparse skeleton behind a `[project.scripts]` entry point.
+//! - `service`: a FastAPI app with a `/health` endpoint, run by
+//!   `uv run <name>`.
+//! - `notebook`: `notebooks/` with a starter notebook, a Jupyter kernel for
+//!   the project's `.venv`, and nbstripout so outputs stay out of git.
+//! - `rust-ext`: a PyO3/maturin project from `uv init`, checked after
+//!   `uv sync` by importing the compiled module.
+
+use anyhow::Result;
+use serde_json::json;
+use toml_edit::value;
+
+use super::{editor::write_json, run_in, Project};
+
+/// `uv init` flags for the project type.
+pub fn uv_init_flags(project_type: &str) -> &'static [&'static str] {
+    match project_type {
+        "lib" => &["--lib"],
+        // A mixed project: the Rust crate at the root, the Python package
+        // under src/ re-exporting the compiled `_core` module.
+        "rust-ext" => &["--lib", "--build-backend", "maturin"],
+        // Packaged apps get the src layout and a build backend, which
+        // console scripts need.
+        "cli" | "service" => &["--package"],
+        _ => &["--app"],
+    }
+}
+
+/// Files written before `uv sync`; returns the paths written.
+pub fn write_files(project: &Project) -> Result<Vec<String>> {
+    match project.project_type.as_str() {
+        "cli" => cli(project),
+        "service" => service(project),
+        "notebook" => notebook(project),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Steps that need the synced environment.
+pub fn after_sync(project: &Project) -> Result<()> {
+    match project.project_type.as_str() {
+        "notebook" => strip_outputs(project),
+        "rust-ext" => check_extension(project),
+        _ => Ok(()),
+    }
+}
+
+/// Steps that leave something outside the project, which a rollback would
+/// not remove; run once nothing else can fail.
+pub fn finish(project: &Project) -> Result<()> {
+    match project.project_type.as_str() {
+        "notebook" => register_kernel(project),
+        _ => Ok(()),
+    }
+}
+
+/// `uv sync` built the extension; make sure the package can load it.
+fn check_extension(project: &Project) -> Result<()> {
+    let sp = cliclack::spinner();
+    sp.start("Importing the Rust extension…");
+    let module = format!("{}._core", project.module());
+    run_in(
+        &project.dir,
+        "uv",
+        &["run", "python", "-c", &format!("import {module}")],
+    )?;
+    sp.stop(format!("Rust extension {module} builds and imports"));
+    Ok(())
+}
+
+fn register_kernel(project: &Project) -> Result<()> {
+    let sp = cliclack::spinner();
+    sp.start("Registering Jupyter kernel…");
+    let display_name = format!("Python ({})", project.name);
+    run_in(
+        &project.dir,
+        "uv",
+        &[
+            "run",
+            "python",
+            "-m",
+            "ipykernel",
+            "install",
+            "--user",
+            "--name",
+            &project.name,
+            "--display-name",
+            &display_name,
+        ],
+    )?;
+    sp.stop(format!("Jupyter kernel `{}` registered", project.name));
+    Ok(())
+}
+
+/// nbstripout's filter lives in the git config, so it needs a repository.
+fn strip_outputs(project: &Project) -> Result<()> {
+    if !project.git {
+        cliclack::log::info(
+            "No git repository — run `uv run nbstripout --install` after `git init` \
+             to keep notebook outputs out of commits.",
+        )?;
+        return Ok(());
+    }
+    let sp = cliclack::spinner();
+    sp.start("Installing nbstripout…");
+    run_in(
+        &project.dir,
+        "uv",
+        &[
+            "run",
+            "nbstripout",
+            "--install",
+            "--attributes",
+            ".gitattributes",
+        ],
+    )?;
+    sp.stop("nbstripout installed");
+    Ok(())
+}
+
+/// Point the `[project.scripts]` entry named after the project at `target`.
+fn set_script(project: &Project, target: &str) -> Result<bool> {
+    let Some(mut pyproject) = project.pyproject()? else {
+        return Ok(false);
+    };
+    pyproject.table(&["project", "scripts"])?[project.name.as_str()] = value(target);
+    pyproject.save()?;
+    Ok(true)
+}
+
+fn cli(project: &Project) -> Result<Vec<String>> {
+    let module = project.module();
+    let mut written = Vec::new();
+    let rel = format!("src/{module}/cli.py");
+    if project.write(&rel, &cli_py(project))? {
+        written.push(rel);
+    }
+    if set_script(project, &format!("{module}.cli:main"))? {
+        written.push("pyproject.toml (scripts)".to_string());
+    }
+    Ok(written)
+}
+
+fn cli_py(project: &Project) -> String {
+    let name = &project.name;
+    format!(
+        r#""""Command-line interface for {name}."""
+
+from __future__ import annotations
+
+import argparse
+
+
+def build_parser() -> argparse.ArgumentParser:
+    parser = argparse.ArgumentParser(prog="{name}")
+    parser.add_argument("who", nargs="?", default="world", help="who to greet")
+    parser.add_argument("-v", "--verbose", action="store_true", help="print more detail")
+    return parser
+
+
+def main(argv: list[str] | None = None) -> int:
+    args = build_parser().parse_args(argv)
+    if args.verbose:
+        print(f"{name}: greeting {{args.who!r}}")
+    print(f"Hello, {{args.who}}!")
+    return 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
+"#
+    )
+}
+
+fn service(project: &Project) -> Result<Vec<String>> {
+    let module = project.module();
+    let mut written = Vec::new();
+    let rel = format!("src/{module}/app.py");
+    if project.write(&rel, &service_py(project))? {
+        written.push(rel);
+    }
+    if let Some(mut pyproject) = project.pyproject()? {
+        pyproject.add_dependencies(&["fastapi", "uvicorn[standard]"])?;
+        pyproject.save()?;
+        written.push("pyproject.toml (dependencies)".to_string());
+    }
+    if set_script(project, &format!("{module}.app:run"))? {
+        written.push("pyproject.toml (scripts)".to_string());
+    }
+    Ok(written)
+}
+
+fn service_py(project: &Project) -> String {
+    let name = &project.name;
+    let module = project.module();
+    format!(
+        r#""""HTTP service for {name}.
+
+Run it with `uv run {name}`; HOST and PORT override where it listens.
+"""
+
+import os
+
+import uvicorn
+from fastapi import FastAPI
+
+app = FastAPI(title="{name}")
+
+
+@app.get("/health")
+def health() -> dict[str, str]:
+    return {{"status": "ok"}}
+
+
+def run() -> None:
+    uvicorn.run(
+        "{module}.app:app",
+        host=os.environ.get("HOST", "127.0.0.1"),
+        port=int(os.environ.get("PORT", "8000")),
+    )
+"#
+    )
+}
+
+fn notebook(project: &Project) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+    if let Some(mut pyproject) = project.pyproject()? {
+        pyproject.add_to_group("dev", &["ipykernel", "nbstripout"])?;
+        pyproject.save()?;
+        written.push("pyproject.toml (dev group)".to_string());
+    }
+    let rel = "notebooks/explore.ipynb";
+    if write_json(project, rel, &starter_notebook(project))? {
+        written.push(rel.to_string());
+    }
+    Ok(written)
+}
+
+/// An nbformat 4 notebook bound to the kernel registered in `after_sync`.
+fn starter_notebook(project: &Project) -> serde_json::Value {
+    json!({
+        "cells": [
+            {
+                "cell_type": "markdown",
+                "metadata": {},
+                "source": [format!("# {}", project.name)]
+            },
+            {
+                "cell_type": "code",
+                "execution_count": null,
+                "metadata": {},
+                "outputs": [],
+                "source": []
+            }
+        ],
+        "metadata": {
+            "kernelspec": {
+                "name": project.name,
+                "display_name": format!("Python ({})", project.name),
+                "language": "python"
+            },
+            "language_info": { "name": "python" }
+        },
+        "nbformat": 4,
+        "nbformat_minor": 5
+    })
+}
@@ -0,0 +1,71 @@
+//! The license catalog: what the prompt offers, and the SPDX metadata
+//! written into pyproject.toml. The license texts themselves are template
+//! files (`templates/default/licenses/`).
+
+use std::path::Path;
+use toml_edit::{value, Array, Table};
+
+use super::Choices;
+
+pub const LICENSES: Choices = &[
+    ("MIT", "MIT", "permissive"),
+    ("Apache-2.0", "Apache 2.0", "permissive with patent grant"),
+    ("BSD-3-Clause", "BSD 3-Clause", "permissive, no endorsement"),
+    ("BSD-2-Clause", "BSD 2-Clause", "permissive"),
+    ("ISC", "ISC", "permissive, minimal"),
+    ("MPL-2.0", "MPL 2.0", "file-level copyleft"),
+    ("LGPL-3.0", "LGPL 3.0", "library copyleft"),
+    ("GPL-3.0", "GPL 3.0", "copyleft"),
+    ("Unlicense", "Unlicense", "public domain"),
+    ("None", "None", "no license"),
+];
+
+/// SPDX expression and license files for a `LICENSES` value; `None` for
+/// "None".
+fn metadata(license: &str) -> Option<(&'static str, &'static [&'static str])> {
+    Some(match license {
+        "MIT" => ("MIT", &["LICENSE"]),
+        "Apache-2.0" => ("Apache-2.0", &["LICENSE", "NOTICE"]),
+        "BSD-3-Clause" => ("BSD-3-Clause", &["LICENSE"]),
+        "BSD-2-Clause" => ("BSD-2-Clause", &["LICENSE"]),
+        "ISC" => ("ISC", &["LICENSE"]),
+        "MPL-2.0" => ("MPL-2.0", &["LICENSE"]),
+        "LGPL-3.0" => ("LGPL-3.0-or-later", &["LICENSE", "COPYING"]),
+        "GPL-3.0" => ("GPL-3.0-or-later", &["LICENSE"]),
+        "Unlicense" => ("Unlicense", &["LICENSE"]),
+        _ => return None,
+    })
+}
+
+/// Set `license` (PEP 639) and `license-files` in the `[project]` table.
+/// Only files present in `dir` are listed: backends must fail a build on a
+/// pattern that matches nothing, and a template or a merge may not provide
+/// them. PEP 639 also has backends reject license classifiers next to an
+/// SPDX expression, so any are removed.
+pub fn apply(license: &str, dir: &Path, table: &mut Table) {
+    let Some((spdx, files)) = metadata(license) else {
+        return;
+    };
+    table["license"] = value(spdx);
+    let present: Array = files
+        .iter()
+        .copied()
+        .filter(|file| dir.join(file).is_file())
+        .collect();
+    if present.is_empty() {
+        table.remove("license-files");
+    } else {
+        table["license-files"] = value(present);
+    }
+
+    let empty = match table.get_mut("classifiers").and_then(|c| c.as_array_mut()) {
+        Some(list) => {
+            list.retain(|v| !v.as_str().is_some_and(|c| c.starts_with("License ::")));
+            list.is_empty()
+        }
+        None => false,
+    };
+    if empty {
+        table.remove("classifiers");
+    }
+}
@@ -0,0 +1,53 @@
+//! The `[project]` metadata in pyproject.toml, filled in from the answers
+//! in place of the placeholders `uv init` writes.
+
+use anyhow::Result;
+use toml_edit::{value, Array, InlineTable, Table};
+
+use super::{license, Project};
+
+/// Returns whether pyproject.toml was changed.
+pub fn write(project: &Project) -> Result<bool> {
+    let Some(mut pyproject) = project.pyproject()? else {
+        return Ok(false);
+    };
+    let table = pyproject.table(&["project"])?;
+
+    if project.description.is_empty() {
+        table.remove("description");
+    } else {
+        table["description"] = value(&project.description);
+    }
+    if project.dir.join("README.md").exists() {
+        table["readme"] = value("README.md");
+    }
+    table["requires-python"] = value(format!(">={}", project.python_version));
+
+    if !project.author.is_empty() {
+        let mut author = InlineTable::new();
+        author.insert("name", project.author.as_str().into());
+        if let Some(email) = &project.email {
+            author.insert("email", email.as_str().into());
+        }
+        table["authors"] = value(Array::from_iter([author]));
+    }
+
+    license::apply(&project.license, &project.dir, table);
+
+    if !project.repository.is_empty() {
+        set_urls(&project.repository, pyproject.table(&["project", "urls"])?);
+    }
+
+    pyproject.save()?;
+    Ok(true)
+}
+
+fn set_urls(repository: &str, urls: &mut Table) {
+    let repository = repository.trim_end_matches('/').trim_end_matches(".git");
+    urls["Homepage"] = value(repository);
+    urls["Repository"] = value(repository);
+    // Both forges serve the tracker at the same path.
+    if repository.contains("github.com/") || repository.contains("gitlab.com/") {
+        urls["Issues"] = value(format!("{repository}/issues"));
+    }
+}
@@ -0,0 +1,942 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    collections::HashSet,
+    env, fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{
+    checks,
+    cli::NewAppArgs,
+    config::{self, Config, ConfigManager},
+    questions::*,
+    ui,
+};
+
+pub mod ci;
+mod container;
+mod debug;
+mod editor;
+mod kinds;
+mod license;
+mod metadata;
+mod nix;
+mod precommit;
+mod pyproject;
+mod rollback;
+mod script;
+pub mod style;
+mod target;
+mod template;
+mod tooling;
+
+pub use license::LICENSES;
+use ci::Ci;
+use pyproject::Pyproject;
+use style::Indent;
+use target::Existing;
+use template::{Template, Vars};
+
+/// Everything gathered by the prompts that the scaffold steps need.
+pub struct Project {
+    pub name: String,
+    pub description: String,
+    pub python_version: String,
+    pub project_type: String,
+    pub author: String,
+    /// From `git config user.email`.
+    pub email: Option<String>,
+    /// Source repository URL; may be empty.
+    pub repository: String,
+    pub license: String,
+    pub dir: PathBuf,
+    pub indent: Indent,
+    /// Scaffold ruff, pytest and mypy.
+    pub dev_tools: bool,
+    pub ci: Ci,
+    /// Scaffold a Dockerfile and devcontainer.
+    pub docker: bool,
+    /// Scaffold a nix flake dev shell.
+    pub nix: bool,
+    /// Answers to the template's own prompts.
+    pub extra_vars: Vars,
+    /// Paths that existed before a merge; scaffold steps leave them alone.
+    pub keep: HashSet<PathBuf>,
+    /// `dir` was already in a git repository.
+    pub had_repo: bool,
+    /// The project is in a git repository: an existing one, or one created
+    /// unless `--no-git`.
+    pub git: bool,
+    /// What was there before; files are preserved in it before they change.
+    pub snapshot: rollback::Snapshot,
+}
+
+impl Project {
+    /// Importable module name, e.g. `my_project` for `my-project`.
+    pub fn module(&self) -> String {
+        self.name.replace('-', "_")
+    }
+
+    /// Variables available to templates.
+    fn template_vars(&self) -> Vars {
+        let mut vars: Vars = [
+            ("name", self.name.clone()),
+            ("module", self.module()),
+            ("author", self.author.clone()),
+            ("python", self.python_version.clone()),
+            ("type", self.project_type.clone()),
+            ("license", self.license.clone()),
+            ("year", current_year().to_string()),
+            ("description", self.description.clone()),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+        vars.extend(self.extra_vars.clone());
+        vars
+    }
+
+    /// Write `rel` (relative to the project dir), creating parent
+    /// directories. Returns `false` when the file is kept from a merge.
+    pub fn write(&self, rel: &str, contents: &str) -> Result<bool> {
+        let path = self.dir.join(rel);
+        if self.keep.contains(&path) {
+            return Ok(false);
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("cannot create {}", parent.display()))?;
+        }
+        self.snapshot.preserve(&path)?;
+        fs::write(&path, contents).with_context(|| format!("cannot write {}", path.display()))?;
+        Ok(true)
+    }
+
+    /// The project's pyproject.toml for editing, unless it is kept from a
+    /// merge.
+    pub fn pyproject(&self) -> Result<Option<Pyproject>> {
+        let path = self.dir.join("pyproject.toml");
+        if self.keep.contains(&path) {
+            return Ok(None);
+        }
+        self.snapshot.preserve(&path)?;
+        Pyproject::open(&self.dir).map(Some)
+    }
+
+    /// Whether the code lives under `src/<module>/` (packaged types) rather
+    /// than a top-level `main.py`.
+    pub fn src_layout(&self) -> bool {
+        matches!(
+            self.project_type.as_str(),
+            "lib" | "cli" | "service" | "rust-ext"
+        )
+    }
+
+    /// The module that runs the project, or `None` for a library.
+    pub fn entry_module(&self) -> Option<String> {
+        match self.project_type.as_str() {
+            "lib" | "rust-ext" => None,
+            "cli" => Some(format!("{}.cli", self.module())),
+            "service" => Some(format!("{}.app", self.module())),
+            _ => Some("main".to_string()),
+        }
+    }
+}
+
+/// Values pre-filled into the new-app prompts, from `new_app.*` answers.
+struct Defaults {
+    python: String,
+    license: String,
+    project_type: String,
+    projects_root: Option<PathBuf>,
+    remember_last: Option<bool>,
+}
+
+impl Defaults {
+    fn from_config(cfg: &Config) -> Self {
+        // Stored values skip the flags' validation (a bundle can carry
+        // anything), so one this dimos does not offer falls back.
+        let choice = |value: Option<&str>, choices: Choices, fallback: &str| {
+            value
+                .filter(|v| choices.iter().any(|(c, ..)| c == v))
+                .unwrap_or(fallback)
+                .to_string()
+        };
+        Self {
+            python: choice(cfg.get::<NewAppDefaultPython>(), PYTHON_VERSIONS, "3.13"),
+            license: choice(cfg.get::<NewAppDefaultLicense>(), LICENSES, "MIT"),
+            project_type: choice(cfg.get::<NewAppDefaultType>(), PROJECT_TYPES, "app"),
+            projects_root: cfg.get::<NewAppProjectsRoot>().map(PathBuf::from),
+            remember_last: cfg.get::<NewAppRememberLast>().map(|v| v == "true"),
+        }
+    }
+}
+
+/// Selectable values as (value, label, hint); shared by prompts and flags.
+pub type Choices = &'static [(&'static str, &'static str, &'static str)];
+
+pub const PYTHON_VERSIONS: Choices = &[
+    ("3.13", "Python 3.13", "latest stable"),
+    ("3.12", "Python 3.12", ""),
+    ("3.11", "Python 3.11", ""),
+    ("3.10", "Python 3.10", ""),
+    ("3.9", "Python 3.9", ""),
+];
+
+pub const PROJECT_TYPES: Choices = &[
+    ("app", "Application", "runnable script"),
+    ("lib", "Library", "importable package"),
+    ("cli", "CLI", "command-line tool with an entry point"),
+    ("service", "Web service", "FastAPI app with a health endpoint"),
+    ("notebook", "Notebooks", "research notebooks with a project kernel"),
+    ("script", "Script", "single file with inline metadata (PEP 723)"),
+    ("rust-ext", "Rust extension", "PyO3/maturin package with a Rust core"),
+];
+
+/// Clap parser accepting only the values in `choices`.
+pub fn choices(choices: Choices) -> clap::builder::PossibleValuesParser {
+    clap::builder::PossibleValuesParser::new(choices.iter().map(|(value, ..)| *value))
+}
+
+pub fn validate_name(s: &str) -> Result<(), &'static str> {
+    if s.trim().is_empty() {
+        return Err("Name cannot be empty.");
+    }
+    if s.contains(' ') {
+        return Err("Use hyphens instead of spaces (e.g. my-project).");
+    }
+    if !s
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Only alphanumeric characters, hyphens, and underscores.");
+    }
+    Ok(())
+}
+
+/// `--name` parser, with the same rules as the prompt.
+pub fn parse_name(s: &str) -> Result<String, String> {
+    validate_name(s)
+        .map(|()| s.to_string())
+        .map_err(str::to_string)
+}
+
+/// Fills in each value from its flag, a default (under `--yes`) or a
+/// prompt (in a terminal). Values that can be had none of these ways are
+/// collected, so they can all be reported at once.
+struct Gather {
+    interactive: bool,
+    yes: bool,
+    missing: Vec<String>,
+}
+
+impl Gather {
+    fn value<T: Default>(
+        &mut self,
+        flag: &str,
+        given: Option<T>,
+        default: Option<T>,
+        ask: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        if let Some(value) = given {
+            return Ok(value);
+        }
+        if let (true, Some(default)) = (self.yes, default) {
+            return Ok(default);
+        }
+        if self.interactive {
+            return ask();
+        }
+        self.missing.push(flag.to_string());
+        Ok(T::default())
+    }
+}
+
+fn select(prompt: &str, choices: Choices, initial: &str) -> Result<String> {
+    let mut select = cliclack::select(prompt).initial_value(initial);
+    for (value, label, hint) in choices {
+        select = select.item(*value, *label, *hint);
+    }
+    Ok(select.interact()?.to_string())
+}
+
+pub fn run(args: &NewAppArgs) -> Result<()> {
+    // A read-only snapshot: the config lock is not held while prompting.
+    let cfg = config::load()?;
+    if !cfg.init_completed {
+        bail!("Environment not initialised — run `dimos init` first.");
+    }
+    let defaults = Defaults::from_config(&cfg);
+    // Resolved up front so a bad --template fails before any prompt.
+    let template = Template::resolve(args.template.as_deref().unwrap_or("default"))?;
+
+    let mut gather = Gather {
+        interactive: std::io::stdin().is_terminal(),
+        yes: args.yes,
+        missing: Vec::new(),
+    };
+
+    ui::header(" Dimos — New Python App");
+
+    // ── gather answers ────────────────────────────────────────────────────────
+
+    let name = gather.value("--name", args.name.clone(), None, || {
+        Ok(cliclack::input("Project name")
+            .placeholder("my-project")
+            .validate(|s: &String| validate_name(s))
+            .interact()?)
+    })?;
+
+    let description = gather.value(
+        "--description",
+        args.description.clone(),
+        Some(String::new()),
+        || {
+            Ok(cliclack::input("Short description")
+                .placeholder("What does this project do? (optional, Enter to skip)")
+                .default_input("")
+                .interact()?)
+        },
+    )?;
+
+    let python_version = gather.value(
+        "--python",
+        args.python.clone(),
+        Some(defaults.python.clone()),
+        || select("Python version", PYTHON_VERSIONS, &defaults.python),
+    )?;
+
+    let project_type = gather.value(
+        "--type",
+        args.project_type.clone(),
+        Some(defaults.project_type.clone()),
+        || select("Project type", PROJECT_TYPES, &defaults.project_type),
+    )?;
+    let is_script = project_type == "script";
+    if is_script && (args.ci != Ci::None || args.docker || args.nix) {
+        bail!("--ci, --docker and --nix need a project directory; a script is a single file.");
+    }
+
+    // A script has no package metadata to put these in; the license stays
+    // the remembered default so remembering this run's choices keeps it.
+    let default_author = git_config("user.name").unwrap_or_default();
+    let author = if is_script {
+        String::new()
+    } else {
+        gather.value(
+            "--author",
+            args.author.clone(),
+            Some(default_author.clone()),
+            || {
+                Ok(cliclack::input("Author name")
+                    .default_input(&default_author)
+                    .interact()?)
+            },
+        )?
+    };
+
+    let license = if is_script {
+        defaults.license.clone()
+    } else {
+        gather.value(
+            "--license",
+            args.license.clone(),
+            Some(defaults.license.clone()),
+            || select("License", LICENSES, &defaults.license),
+        )?
+    };
+
+    // A guess for GitHub users who have set `github.user`, as gh and hub do.
+    let default_repository = git_config("github.user")
+        .map(|user| format!("https://github.com/{user}/{name}"))
+        .unwrap_or_default();
+    let repository = if is_script {
+        String::new()
+    } else {
+        gather.value(
+            "--repository",
+            args.repository.clone(),
+            Some(default_repository.clone()),
+            || {
+                Ok(cliclack::input("Repository URL")
+                    .placeholder("https://github.com/you/project (optional, Enter to skip)")
+                    .default_input(&default_repository)
+                    .required(false)
+                    .interact()?)
+            },
+        )?
+    };
+
+    let mut extra_vars = Vars::new();
+    // Scripts are generated by `uv init --script`, not from the template.
+    let prompts = if is_script { &[] } else { template.prompts() };
+    let mut given = Vars::new();
+    for pair in &args.vars {
+        let Some((key, value)) = pair.split_once('=') else {
+            bail!("--var expects KEY=VALUE, got `{pair}`");
+        };
+        given.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    for prompt in prompts {
+        let flag = format!("--var {}=…", prompt.key);
+        let value = given.remove(&prompt.key);
+        if let Some(value) = &value {
+            prompt
+                .validate(value)
+                .map_err(|e| anyhow!("--var {}: {e}", prompt.key))?;
+        }
+        let answer = gather.value(&flag, value, prompt.default_value(), || prompt.ask())?;
+        extra_vars.insert(prompt.key.clone(), answer);
+    }
+    if let Some(key) = given.keys().next() {
+        bail!("--var {key}: template `{}` has no such prompt", template.name);
+    }
+
+    let root = match defaults.projects_root {
+        Some(ref root) => root.clone(),
+        None => env::current_dir()?,
+    };
+    let default_dir = root.join(&name);
+    let project_dir = gather.value(
+        "--dir",
+        args.dir.clone(),
+        Some(default_dir.clone()),
+        || {
+            let dir: String = cliclack::input("Create project in")
+                .default_input(&default_dir.to_string_lossy())
+                .interact()?;
+            Ok(PathBuf::from(dir))
+        },
+    )?;
+
+    if !gather.missing.is_empty() {
+        bail!(
+            "Missing {} — pass these as flags, add --yes to use the defaults, \
+             or run in a terminal to be asked.",
+            gather.missing.join(", ")
+        );
+    }
+
+    let project_dir = target::resolve(&project_dir)?;
+    // A script only needs its own file to be free; whatever else is in the
+    // directory is left alone.
+    let (target_path, occupied) = if is_script {
+        let path = project_dir.join(format!("{name}.py"));
+        let occupied = target::inspect_file(&path)?;
+        (path, occupied)
+    } else {
+        (project_dir.clone(), target::inspect(&project_dir)?)
+    };
+    let existing = match occupied {
+        None => None,
+        Some(_) if args.force => Some(Existing::Force),
+        Some(_) if args.merge => Some(Existing::Merge),
+        Some(occupied) if gather.interactive => Some(target::ask(&target_path, &occupied)?),
+        Some(_) if is_script => bail!(
+            "{} already exists — pass --force to overwrite it.",
+            target_path.display()
+        ),
+        Some(_) => bail!(
+            "{} already exists and is not empty — pass --merge to add only missing \
+             files, or --force to overwrite.",
+            project_dir.display()
+        ),
+    };
+    if existing == Some(Existing::Refuse) {
+        ui::outro_cancel("Cancelled — choose another directory.");
+        return Ok(());
+    }
+
+    // The user's own style, unless the project imposes a shared one.
+    let indent = args
+        .indent
+        .or_else(|| cfg.get::<PersonalityIndentation>().and_then(Indent::from_answer))
+        .unwrap_or(Indent::Spaces);
+
+    // ── summary ───────────────────────────────────────────────────────────────
+
+    let metadata = if is_script {
+        String::new()
+    } else {
+        format!("\nAuthor:  {author}\nLicense: {license}")
+    };
+    let mut lines = format!(
+        "Name:    {name}\nType:    {project_type}\nPython:  {python_version}{metadata}\nDir:     {}",
+        project_dir.display()
+    );
+    if !description.is_empty() {
+        lines = format!("Name:    {name}\nDesc:    {description}\nType:    {project_type}\nPython:  {python_version}{metadata}\nDir:     {}", project_dir.display());
+    }
+    if !repository.is_empty() {
+        lines.push_str(&format!("\nRepo:    {repository}"));
+    }
+    if args.no_git {
+        lines.push_str("\nGit:     no repository");
+    }
+    if !args.no_dev_tools && !is_script {
+        lines.push_str("\nDev:     ruff, pytest, mypy");
+    }
+    match args.ci {
+        Ci::Github => lines.push_str("\nCI:      GitHub Actions"),
+        Ci::Gitlab => lines.push_str("\nCI:      GitLab CI"),
+        Ci::None => {}
+    }
+    let nix_python = nix::python_attribute(&python_version).is_some();
+    if args.nix && !nix_python {
+        bail!("--nix: nixpkgs has no Python {python_version}; choose --python 3.10 or newer.");
+    }
+    let nix_host = !args.no_nix && !is_script && nix::is_nix_host(&cfg);
+    if nix_host && !nix_python {
+        cliclack::log::warning(format!(
+            "No flake.nix: nixpkgs has no Python {python_version}."
+        ))?;
+    }
+    let nix = args.nix || (nix_host && nix_python);
+    if nix {
+        lines.push_str("\nNix:     flake.nix dev shell");
+    }
+    if args.docker {
+        lines.push_str("\nDocker:  Dockerfile + devcontainer");
+    }
+    if !is_script {
+        lines.push_str(&format!("\nTemplate: {}", template.name));
+        if !template.description().is_empty() {
+            lines.push_str(&format!(" — {}", template.description()));
+        }
+    }
+    for (key, value) in &extra_vars {
+        lines.push_str(&format!("\n  {key}: {value}"));
+    }
+    match existing {
+        Some(Existing::Merge) => lines.push_str("\nExisting: merge — only missing files are added"),
+        Some(Existing::Force) => {
+            lines.push_str("\nExisting: overwrite — generated files replace existing ones")
+        }
+        _ => {}
+    }
+    cliclack::note("Summary", lines)?;
+
+    // Without a terminal every value came from a flag, so intent is clear.
+    let confirmed = args.yes
+        || !gather.interactive
+        || cliclack::confirm("Create this project?")
+            .initial_value(true)
+            .interact()?;
+
+    if !confirmed {
+        ui::outro_cancel("Cancelled.");
+        return Ok(());
+    }
+
+    // ── create ────────────────────────────────────────────────────────────────
+
+    // maturin builds the extension with cargo during `uv sync`.
+    if project_type == "rust-ext" {
+        let cargo = checks::cargo::check();
+        if gather.interactive {
+            cargo.run(&cfg)?;
+        } else if !(cargo.detect)(&cfg) {
+            bail!("cargo is required for rust-ext projects — install Rust with rustup: https://rustup.rs");
+        }
+    }
+
+    // A script added to a folder of other files doesn't make it a repository,
+    // and a rollback must not touch anything in it but the script.
+    let shared_dir = is_script && target::inspect(&project_dir)?.is_some();
+    let snapshot = rollback::Snapshot::take(if shared_dir {
+        &target_path
+    } else {
+        &project_dir
+    })?;
+    let had_repo = target::repo_root(&project_dir).is_some();
+    let project = Project {
+        name,
+        description,
+        python_version: python_version.clone(),
+        project_type: project_type.clone(),
+        author,
+        email: git_config("user.email"),
+        repository,
+        license: license.clone(),
+        dir: project_dir.clone(),
+        indent,
+        dev_tools: !args.no_dev_tools && !is_script,
+        ci: args.ci,
+        docker: args.docker,
+        nix,
+        extra_vars,
+        keep: match existing {
+            Some(Existing::Merge) => snapshot.existing().clone(),
+            _ => HashSet::new(),
+        },
+        had_repo,
+        git: had_repo || (!args.no_git && !shared_dir),
+        snapshot,
+    };
+    if let Err(e) = scaffold(&project, &cfg, &template) {
+        // The scaffold error is the one worth seeing; a failed cleanup is
+        // reported alongside it.
+        if let Err(cleanup) = clean_up_failed(&project.snapshot, args.keep_on_failure, gather.interactive) {
+            cliclack::log::warning(format!("Could not clean up: {cleanup:#}"))?;
+        }
+        return Err(e);
+    }
+
+    remember_choices(
+        &defaults,
+        gather.interactive && !args.yes,
+        &python_version,
+        &project_type,
+        &license,
+        &project_dir,
+    )?;
+
+    if args.no_shell {
+        ui::outro(format!("Project ready in {}", project_dir.display()));
+        return Ok(());
+    }
+
+    // ── open shell in project dir ─────────────────────────────────────────────
+
+    ui::outro(format!(
+        "Project ready — opening a shell in {}  (type `exit` to return)",
+        project_dir.display()
+    ));
+
+    let shell = env::var("SHELL").unwrap_or_else(|_| "sh".to_string());
+    Command::new(&shell)
+        .current_dir(&project_dir)
+        .status()
+        .with_context(|| format!("failed to spawn {shell}"))?;
+
+    Ok(())
+}
+
+/// After a failed scaffold, remove what it created unless asked to keep it.
+/// Without a terminal the partial project is removed, so a retry starts
+/// clean.
+fn clean_up_failed(snapshot: &rollback::Snapshot, keep_flag: bool, interactive: bool) -> Result<()> {
+    let created = snapshot.created()?;
+    let changed = snapshot.changed();
+    if created.is_empty() && changed.is_empty() {
+        return Ok(());
+    }
+    let join = |paths: &[PathBuf]| {
+        paths
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let list = join(&created);
+
+    let keep = keep_flag
+        || (interactive
+            && cliclack::select("Scaffolding failed. What should happen to the partial project?")
+                .item(false, "Remove it", list.as_str())
+                .item(true, "Keep it", "for inspection")
+                .interact()?);
+
+    if keep {
+        cliclack::log::warning(format!("Kept for inspection: {list}"))?;
+    } else {
+        snapshot.roll_back()?;
+        if !created.is_empty() {
+            cliclack::log::info(format!("Removed: {list}"))?;
+        }
+        if !changed.is_empty() {
+            cliclack::log::info(format!("Restored: {}", join(&changed)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Store this run's choices as the next run's defaults, if the user opted in.
+/// The opt-in itself is asked once, the first time a project is created.
+fn remember_choices(
+    defaults: &Defaults,
+    ask: bool,
+    python_version: &str,
+    project_type: &str,
+    license: &str,
+    project_dir: &Path,
+) -> Result<()> {
+    let remember = match defaults.remember_last {
+        Some(false) => return Ok(()),
+        Some(true) => true,
+        None if ask => {
+            cliclack::confirm("Remember these choices as defaults for next time?")
+                .initial_value(true)
+                .interact()?
+        }
+        None => return Ok(()),
+    };
+
+    let mut mgr = ConfigManager::load_or_recover()?;
+    mgr.set::<NewAppRememberLast>(remember.to_string());
+    if remember {
+        mgr.set::<NewAppDefaultPython>(python_version.to_string());
+        mgr.set::<NewAppDefaultType>(project_type.to_string());
+        mgr.set::<NewAppDefaultLicense>(license.to_string());
+        // A script's directory is a folder of tools, not a project next to
+        // other projects.
+        if let Some(parent) = project_dir.parent().filter(|_| project_type != "script") {
+            mgr.set::<NewAppProjectsRoot>(parent.to_string_lossy().into_owned());
+        }
+    }
+    mgr.save()
+}
+
+// ── scaffolding steps ─────────────────────────────────────────────────────────
+
+fn scaffold(project: &Project, cfg: &Config, template: &Template) -> Result<()> {
+    let dir = &project.dir;
+
+    let sp = cliclack::spinner();
+    sp.start("Creating directory…");
+    fs::create_dir_all(dir)?;
+    sp.stop("Directory created");
+
+    if project.git && !project.had_repo {
+        let sp = cliclack::spinner();
+        sp.start("Initialising git repository…");
+        run_in(dir, "git", &["init", "-q"])?;
+        sp.stop("git repository initialised");
+    }
+
+    if project.project_type == "script" {
+        script::write(project)?;
+        return commit(project);
+    }
+
+    // Files dimos writes itself are preserved as they are written; these
+    // are edited in place by uv, nbstripout and the hook installers.
+    for rel in ["uv.lock", ".gitattributes"] {
+        project.snapshot.preserve(&dir.join(rel))?;
+    }
+    if project.had_repo {
+        if let Some(git_dir) = git_dir(dir) {
+            for rel in GIT_FILES_EDITED {
+                project.snapshot.preserve(&git_dir.join(rel))?;
+            }
+        }
+    }
+
+    if project.git {
+        let sp = cliclack::spinner();
+        sp.start("Installing git-lfs hooks…");
+        run_in(dir, "git", &["lfs", "install", "--local", "--silent"])?;
+        sp.stop("git-lfs installed");
+    }
+
+    let pyproject = dir.join("pyproject.toml");
+    if project.keep.contains(&pyproject) {
+        cliclack::log::info("Keeping the existing pyproject.toml")?;
+    } else {
+        // Only reachable with --force: `uv init` refuses to overwrite it.
+        if pyproject.exists() {
+            project.snapshot.preserve(&pyproject)?;
+            fs::remove_file(&pyproject)
+                .with_context(|| format!("cannot remove {}", pyproject.display()))?;
+        }
+        uv_init(project)?;
+    }
+
+    let sp = cliclack::spinner();
+    sp.start(format!("Rendering template {}…", template.name));
+    let mut written = Vec::new();
+    for (rel, text) in template.render_files(&project.template_vars())? {
+        if project.write(&rel, &text)? {
+            written.push(rel);
+        }
+    }
+    written.extend(style::write_files(project)?.into_iter().map(String::from));
+    if metadata::write(project)? {
+        written.push("pyproject.toml (metadata)".to_string());
+    }
+    written.extend(kinds::write_files(project)?);
+    sp.stop(format!("Written: {}", written.join(", ")));
+
+    let editor = cfg.get::<PersonalityEditor>();
+    if let Some(editor) = editor {
+        let written = editor::write_files(project, editor)?;
+        if !written.is_empty() {
+            cliclack::log::success(format!("Editor settings written: {}", written.join(", ")))?;
+        }
+    }
+
+    if let Some(debug_style) = cfg.get::<PersonalityDebugStyle>() {
+        let written = debug::write_files(project, debug_style, editor)?;
+        if !written.is_empty() {
+            cliclack::log::success(format!("Debugging support written: {}", written.join(", ")))?;
+        }
+    }
+
+    if project.dev_tools {
+        let written = tooling::write_files(project)?;
+        if !written.is_empty() {
+            cliclack::log::success(format!("Dev tooling written: {}", written.join(", ")))?;
+        }
+    }
+
+    if precommit::write_config(project)? {
+        cliclack::log::success(format!("Commit hooks written: {}", precommit::CONFIG))?;
+    }
+
+    if let Some(rel) = ci::write_files(project, project.ci)? {
+        cliclack::log::success(format!("CI pipeline written: {rel}"))?;
+    }
+
+    if project.docker {
+        let written = container::write_files(project)?;
+        if !written.is_empty() {
+            cliclack::log::success(format!("Container files written: {}", written.join(", ")))?;
+        }
+    }
+
+    if project.nix {
+        let written = nix::write_files(project)?;
+        if !written.is_empty() {
+            cliclack::log::success(format!("Nix dev shell written: {}", written.join(", ")))?;
+        }
+    }
+
+    // After the files above, so dependency groups they add get installed.
+    let sp = cliclack::spinner();
+    sp.start("Creating virtual environment…");
+    run_in(dir, "uv", &["sync"])?;
+    sp.stop("Virtual environment ready");
+
+    kinds::after_sync(project)?;
+
+    if project.git && project.dir.join(precommit::CONFIG).exists() {
+        precommit::install(project)?;
+    }
+
+    commit(project)?;
+    kinds::finish(project)
+}
+
+/// The initial commit, in a repository dimos created.
+fn commit(project: &Project) -> Result<()> {
+    if !project.git {
+        return Ok(());
+    }
+    // A pre-existing repo has history and maybe work in progress; the user
+    // reviews and commits the additions themselves.
+    if project.had_repo {
+        cliclack::log::info("Existing repository — review and commit the new files yourself.")?;
+        return Ok(());
+    }
+
+    let dir = &project.dir;
+    let sp = cliclack::spinner();
+    sp.start("Creating initial commit…");
+    run_in(dir, "git", &["add", "."])?;
+    run_in(
+        dir,
+        "git",
+        // The hooks' first run fetches every hook repository, which needs the
+        // network and can take minutes; the scaffold's own commit skips them.
+        &[
+            "commit",
+            "-q",
+            "--no-verify",
+            "-m",
+            "Initial commit (dimos new-app)",
+        ],
+    )?;
+    sp.stop("Initial commit created");
+
+    Ok(())
+}
+
+fn uv_init(project: &Project) -> Result<()> {
+    let sp = cliclack::spinner();
+    sp.start("Creating uv project…");
+    let mut uv_args: Vec<&str> = vec![
+        "init",
+        "--python",
+        &project.python_version,
+        "--name",
+        &project.name,
+    ];
+    uv_args.extend(kinds::uv_init_flags(&project.project_type));
+    uv_args.push(".");
+    run_in(&project.dir, "uv", &uv_args)?;
+    sp.stop("uv project created");
+    Ok(())
+}
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn run_in(dir: &Path, cmd: &str, args: &[&str]) -> Result<()> {
+    let out = Command::new(cmd)
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("failed to run `{cmd}`"))?;
+
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        bail!("`{cmd} {}` failed:\n{}", args.join(" "), stderr.trim());
+    }
+    Ok(())
+}
+
+/// Files in an existing repository's git directory that `git lfs install`,
+/// `pre-commit install` and `nbstripout --install` write.
+const GIT_FILES_EDITED: &[&str] = &[
+    "config",
+    "hooks/pre-commit",
+    "hooks/pre-push",
+    "hooks/post-checkout",
+    "hooks/post-commit",
+    "hooks/post-merge",
+];
+
+/// The git directory of the repository `dir` is in.
+fn git_dir(dir: &Path) -> Option<PathBuf> {
+    Command::new("git")
+        .args(["rev-parse", "--absolute-git-dir"])
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| PathBuf::from(s.trim()))
+}
+
+fn git_config(key: &str) -> Option<String> {
+    Command::new("git")
+        .args(["config", key])
+        .output()
+        .ok()
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// The current year (UTC), from the proleptic Gregorian calendar.
+fn current_year() -> i64 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    civil_year(secs as i64 / 86_400)
+}
+
+/// Year of the date `days` after 1970-01-01 (Howard Hinnant's
+/// `civil_from_days`, using 400-year eras starting on 1 March).
+fn civil_year(days: i64) -> i64 {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    yoe + era * 400 + i64::from(month <= 2)
+}
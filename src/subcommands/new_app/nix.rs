@@ -0,0 +1,92 @@
+//! A nix flake dev shell (`flake.nix` + `.envrc`) with uv, the project's
+//! Python and the native libraries dimos projects build against. On by
+//! default when the last `dimos init` ran a nix sequence.
+
+use anyhow::{Context, Result};
+
+use super::Project;
+use crate::config::Config;
+
+/// Whether the recorded install sequence is nix-based.
+pub fn is_nix_host(cfg: &Config) -> bool {
+    cfg.install_sequence
+        .as_deref()
+        .is_some_and(|s| s.ends_with("_nix"))
+}
+
+/// The nixpkgs attribute for a Python version; `None` for versions
+/// nixos-unstable no longer ships.
+pub fn python_attribute(version: &str) -> Option<&'static str> {
+    match version {
+        "3.13" => Some("python313"),
+        "3.12" => Some("python312"),
+        "3.11" => Some("python311"),
+        "3.10" => Some("python310"),
+        _ => None,
+    }
+}
+
+/// Write the flake and `.envrc`; returns the paths written.
+pub fn write_files(project: &Project) -> Result<Vec<&'static str>> {
+    let python = python_attribute(&project.python_version)
+        .with_context(|| format!("nixpkgs has no Python {}", project.python_version))?;
+    let mut written = Vec::new();
+    if project.write("flake.nix", &flake(project, python))? {
+        written.push("flake.nix");
+    }
+    if project.write(".envrc", "use flake\n")? {
+        written.push(".envrc");
+    }
+    Ok(written)
+}
+
+fn flake(project: &Project, python: &str) -> String {
+    let name = &project.name;
+    // maturin builds the extension with the Rust toolchain.
+    let rust = if project.project_type == "rust-ext" {
+        "\n            pkgs.cargo\n            pkgs.rustc"
+    } else {
+        ""
+    };
+    format!(
+        r#"{{
+  description = "{name} development shell";
+
+  inputs = {{
+    nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+    flake-utils.url = "github:numtide/flake-utils";
+  }};
+
+  outputs = {{ nixpkgs, flake-utils, ... }}:
+    flake-utils.lib.eachDefaultSystem (system:
+      let
+        pkgs = nixpkgs.legacyPackages.${{system}};
+        python = pkgs.{python};
+        libraries = [ pkgs.portaudio pkgs.libjpeg_turbo ];
+      in
+      {{
+        devShells.default = pkgs.mkShell {{
+          packages = [
+            pkgs.uv
+            python
+            pkgs.git-lfs
+            pkgs.pkg-config{rust}
+          ] ++ libraries;
+
+          env = {{
+            # uv uses this Python instead of downloading its own.
+            UV_PYTHON = "${{python}}/bin/python";
+            UV_PYTHON_DOWNLOADS = "never";
+            # For wheels that dlopen the libraries at runtime.
+            LD_LIBRARY_PATH = pkgs.lib.makeLibraryPath libraries;
+          }};
+
+          shellHook = ''
+            unset PYTHONPATH
+          '';
+        }};
+      }});
+}}
+"#
+    )
+}
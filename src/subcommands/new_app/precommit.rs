@@ -0,0 +1,71 @@
+//! `.pre-commit-config.yaml`: ruff plus repository hygiene hooks
+//! (whitespace, large files, LFS pointers).
+
+use anyhow::Result;
+use std::fs;
+use which::which;
+
+use super::{run_in, target, Project};
+
+pub const CONFIG: &str = ".pre-commit-config.yaml";
+
+/// Write the config; returns whether it was written.
+pub fn write_config(project: &Project) -> Result<bool> {
+    project.write(CONFIG, &config())
+}
+
+/// Install the git hook, or warn when pre-commit is not installed or the
+/// project is not the top level of its repository: the hook would belong to
+/// the enclosing repository, whose root has no config of its own.
+pub fn install(project: &Project) -> Result<()> {
+    let root = target::repo_root(&project.dir);
+    if root.is_some() && root != fs::canonicalize(&project.dir).ok() {
+        cliclack::log::warning(format!(
+            "{} is inside an existing repository — its hooks are not installed. \
+             Add them to that repository's {CONFIG} instead.",
+            project.dir.display()
+        ))?;
+        return Ok(());
+    }
+    if which("pre-commit").is_err() {
+        cliclack::log::warning(format!(
+            "pre-commit not found — {CONFIG} was written but its hooks are not installed. \
+             Run `pre-commit install` in the project once pre-commit is available."
+        ))?;
+        return Ok(());
+    }
+    run_in(&project.dir, "pre-commit", &["install"])
+}
+
+fn config() -> String {
+    [
+        "# Hooks run on every commit; update revisions with `pre-commit autoupdate`.",
+        "repos:",
+        "  - repo: https://github.com/pre-commit/pre-commit-hooks",
+        "    rev: v5.0.0",
+        "    hooks:",
+        "      - id: trailing-whitespace",
+        "      - id: end-of-file-fixer",
+        "      - id: check-yaml",
+        "      - id: check-toml",
+        "      # Files tracked by git-lfs are exempt; anything else this big belongs there.",
+        "      - id: check-added-large-files",
+        "        args: [--maxkb=1024]",
+        "  - repo: https://github.com/astral-sh/ruff-pre-commit",
+        "    rev: v0.11.0",
+        "    hooks:",
+        "      - id: ruff",
+        "        args: [--fix]",
+        "      - id: ruff-format",
+        "  - repo: local",
+        "    hooks:",
+        "      - id: lfs-pointers",
+        "        name: git-lfs pointers are valid",
+        "        entry: git lfs fsck --pointers",
+        "        language: system",
+        "        pass_filenames: false",
+        "        always_run: true",
+    ]
+    .join("\n")
+        + "\n"
+}
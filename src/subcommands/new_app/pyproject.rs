@@ -0,0 +1,80 @@
+//! Format-preserving edits to the `pyproject.toml` generated by `uv init`.
+
+use anyhow::{bail, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use toml_edit::{DocumentMut, Item, Table};
+
+pub struct Pyproject {
+    path: PathBuf,
+    pub doc: DocumentMut,
+}
+
+impl Pyproject {
+    pub fn open(dir: &Path) -> Result<Self> {
+        let path = dir.join("pyproject.toml");
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        let doc = text
+            .parse::<DocumentMut>()
+            .with_context(|| format!("cannot parse {}", path.display()))?;
+        Ok(Self { path, doc })
+    }
+
+    /// The table at `path` (e.g. `["tool", "ruff", "format"]`), created if
+    /// missing. Intermediate tables are implicit so no empty `[tool]` header
+    /// is emitted.
+    pub fn table(&mut self, path: &[&str]) -> Result<&mut Table> {
+        let mut table = self.doc.as_table_mut();
+        for (i, key) in path.iter().enumerate() {
+            let last = i + 1 == path.len();
+            let item = table.entry(key).or_insert_with(|| {
+                let mut t = Table::new();
+                t.set_implicit(!last);
+                Item::Table(t)
+            });
+            table = match item.as_table_mut() {
+                Some(t) => t,
+                None => bail!("`{}` in pyproject.toml is not a table", path[..=i].join(".")),
+            };
+        }
+        Ok(table)
+    }
+
+    /// Add packages to `[project] dependencies`, skipping any already listed.
+    pub fn add_dependencies(&mut self, packages: &[&str]) -> Result<()> {
+        let project = self.table(&["project"])?;
+        extend_array(project, "dependencies", "project.dependencies", packages)
+    }
+
+    /// Add packages to a PEP 735 `[dependency-groups]` group, skipping any
+    /// already listed.
+    pub fn add_to_group(&mut self, group: &str, packages: &[&str]) -> Result<()> {
+        let groups = self.table(&["dependency-groups"])?;
+        extend_array(groups, group, &format!("dependency-groups.{group}"), packages)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.doc.to_string())
+            .with_context(|| format!("cannot write {}", self.path.display()))
+    }
+}
+
+/// Append `packages` to the array `table[key]` (created if missing), named
+/// `dotted` in errors.
+fn extend_array(table: &mut Table, key: &str, dotted: &str, packages: &[&str]) -> Result<()> {
+    let list = table
+        .entry(key)
+        .or_insert_with(|| toml_edit::value(toml_edit::Array::new()));
+    let Some(array) = list.as_array_mut() else {
+        bail!("`{dotted}` in pyproject.toml is not an array");
+    };
+    for package in packages {
+        if !array.iter().any(|v| v.as_str() == Some(package)) {
+            array.push(*package);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Undoing a scaffold that failed partway: everything it created is
+//! removed, and every file it overwrote or deleted is restored from a copy
+//! taken just before the change.
+
+use anyhow::{Context, Result};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// What existed at (and under) the project path before scaffolding began.
+/// The path may also be a single file, e.g. a script added to a directory
+/// that dimos does not own.
+pub struct Snapshot {
+    /// The outermost directory the scaffold will create, if any; otherwise
+    /// the existing project directory.
+    root: PathBuf,
+    /// Everything under `root` that already existed.
+    existing: HashSet<PathBuf>,
+    /// Where originals are copied before they change; created on first use
+    /// and removed when the snapshot is dropped.
+    backup_dir: PathBuf,
+    /// Original path → its copy in `backup_dir`.
+    preserved: RefCell<BTreeMap<PathBuf, PathBuf>>,
+}
+
+impl Snapshot {
+    pub fn take(dir: &Path) -> Result<Self> {
+        let mut root = dir.to_path_buf();
+        while let Some(parent) = root.parent() {
+            if parent.as_os_str().is_empty() || parent.exists() {
+                break;
+            }
+            root = parent.to_path_buf();
+        }
+        let mut existing = HashSet::new();
+        if root.exists() {
+            existing.insert(root.clone());
+        }
+        if root.is_dir() {
+            walk(&root, &mut |path| {
+                existing.insert(path.to_path_buf());
+                true
+            })?;
+        }
+        Ok(Self {
+            root,
+            existing,
+            backup_dir: env::temp_dir().join(format!("dimos-rollback-{}", process::id())),
+            preserved: RefCell::new(BTreeMap::new()),
+        })
+    }
+
+    /// Copy `path` aside before it is overwritten or deleted. Files this
+    /// scaffold created, and ones already preserved, are left alone: the
+    /// first copy is the original.
+    pub fn preserve(&self, path: &Path) -> Result<()> {
+        let created_here = path.starts_with(&self.root) && !self.existing.contains(path);
+        if created_here || !path.is_file() || self.preserved.borrow().contains_key(path) {
+            return Ok(());
+        }
+        fs::create_dir_all(&self.backup_dir)
+            .with_context(|| format!("cannot create {}", self.backup_dir.display()))?;
+        let copy = self
+            .backup_dir
+            .join(self.preserved.borrow().len().to_string());
+        fs::copy(path, &copy).with_context(|| format!("cannot back up {}", path.display()))?;
+        self.preserved.borrow_mut().insert(path.to_path_buf(), copy);
+        Ok(())
+    }
+
+    /// Files that existed before and have been overwritten or deleted since.
+    pub fn changed(&self) -> Vec<PathBuf> {
+        self.preserved.borrow().keys().cloned().collect()
+    }
+
+    /// Everything that existed before, as absolute paths.
+    pub fn existing(&self) -> &HashSet<PathBuf> {
+        &self.existing
+    }
+
+    /// The outermost paths created since the snapshot.
+    pub fn created(&self) -> Result<Vec<PathBuf>> {
+        let mut created = Vec::new();
+        if !self.root.exists() {
+            return Ok(created);
+        }
+        if !self.existing.contains(&self.root) {
+            created.push(self.root.clone());
+            return Ok(created);
+        }
+        if !self.root.is_dir() {
+            return Ok(created);
+        }
+        walk(&self.root, &mut |path| {
+            if self.existing.contains(path) {
+                return true;
+            }
+            created.push(path.to_path_buf());
+            false
+        })?;
+        Ok(created)
+    }
+
+    /// Remove everything created since the snapshot and restore every file
+    /// that was overwritten or deleted.
+    pub fn roll_back(&self) -> Result<()> {
+        for path in self.created()? {
+            let removed = if path.is_dir() && !path.is_symlink() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+            removed.with_context(|| format!("cannot remove {}", path.display()))?;
+        }
+        for (path, copy) in self.preserved.borrow().iter() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("cannot create {}", parent.display()))?;
+            }
+            fs::copy(copy, path).with_context(|| format!("cannot restore {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.backup_dir);
+    }
+}
+
+/// Visit every path under `dir`; `visit` returns whether to descend into a
+/// directory. Symlinks are visited but never followed.
+fn walk(dir: &Path, visit: &mut dyn FnMut(&Path) -> bool) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        let descend = visit(&path);
+        if descend && entry.file_type()?.is_dir() {
+            walk(&path, visit)?;
+        }
+    }
+    Ok(())
+}
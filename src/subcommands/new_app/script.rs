@@ -0,0 +1,82 @@
+//! `script` projects: a single `<name>.py` with PEP 723 inline metadata
+//! (dependencies and requires-python), created by `uv init --script` and
+//! made directly executable through a `uv run --script` shebang. `--dir`
+//! can name an existing directory of tools; only `<name>.py` has to be free.
+
+use anyhow::{Context, Result};
+use std::{fs, path::Path};
+
+use super::{run_in, Project};
+
+/// `env -S` splits the arguments; plain `env` would look for a program
+/// named "uv run --script".
+const SHEBANG: &str = "#!/usr/bin/env -S uv run --script";
+
+/// Create the script, unless it is kept from a merge.
+pub fn write(project: &Project) -> Result<()> {
+    let rel = format!("{}.py", project.name);
+    let path = project.dir.join(&rel);
+    if project.keep.contains(&path) {
+        cliclack::log::info(format!("Keeping the existing {rel}"))?;
+        return Ok(());
+    }
+    // Only reachable with --force: `uv init` refuses to overwrite it.
+    if path.exists() {
+        project.snapshot.preserve(&path)?;
+        fs::remove_file(&path).with_context(|| format!("cannot remove {}", path.display()))?;
+    }
+
+    let sp = cliclack::spinner();
+    sp.start("Creating script…");
+    run_in(
+        &project.dir,
+        "uv",
+        &[
+            "init",
+            "--script",
+            &rel,
+            "--python",
+            &project.python_version,
+        ],
+    )?;
+    let text =
+        fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+    fs::write(&path, with_header(project, &text))
+        .with_context(|| format!("cannot write {}", path.display()))?;
+    make_executable(&path)?;
+    sp.stop(format!("Script created — run it with ./{rel}"));
+    Ok(())
+}
+
+/// The shebang on top and, when there is a description, a docstring after
+/// the metadata block (comments don't count, so it is still the module's).
+fn with_header(project: &Project, text: &str) -> String {
+    const END: &str = "\n# ///\n";
+    match text.find(END) {
+        Some(i) if !project.description.is_empty() => {
+            let (metadata, rest) = text.split_at(i + END.len());
+            format!(
+                "{SHEBANG}\n{metadata}\n\"\"\"{}\"\"\"\n{rest}",
+                project.description
+            )
+        }
+        _ => format!("{SHEBANG}\n{text}"),
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)
+        .with_context(|| format!("cannot read {}", path.display()))?
+        .permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(path, permissions)
+        .with_context(|| format!("cannot make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Code style files generated from the indentation answer.
+
+use anyhow::Result;
+use clap::ValueEnum;
+
+use super::Project;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Indent {
+    Spaces,
+    Tabs,
+}
+
+impl Indent {
+    /// Parse a `personality.indentation` answer.
+    pub fn from_answer(answer: &str) -> Option<Self> {
+        match answer {
+            "spaces" => Some(Indent::Spaces),
+            "tabs" => Some(Indent::Tabs),
+            _ => None,
+        }
+    }
+}
+
+/// `.editorconfig` plus the matching `[tool.ruff]` settings in pyproject.toml;
+/// returns the paths written.
+pub fn write_files(project: &Project) -> Result<Vec<&'static str>> {
+    let mut written = Vec::new();
+    if project.write(".editorconfig", &editorconfig(project))? {
+        written.push(".editorconfig");
+    }
+
+    let Some(mut pyproject) = project.pyproject()? else {
+        return Ok(written);
+    };
+    pyproject.table(&["tool", "ruff", "format"])?["indent-style"] =
+        toml_edit::value(match project.indent {
+            Indent::Spaces => "space",
+            Indent::Tabs => "tab",
+        });
+    pyproject.save()?;
+    written.push("pyproject.toml (ruff)");
+    Ok(written)
+}
+
+fn editorconfig(project: &Project) -> String {
+    let style = match project.indent {
+        Indent::Spaces => "space",
+        Indent::Tabs => "tab",
+    };
+    // YAML forbids tabs, so data files always use spaces.
+    format!(
+        "\
+root = true
+
+[*]
+charset = utf-8
+end_of_line = lf
+insert_final_newline = true
+trim_trailing_whitespace = true
+
+[*.py]
+indent_style = {style}
+indent_size = 4
+
+[*.{{toml,yaml,yml,json}}]
+indent_style = space
+indent_size = 2
+
+[*.md]
+trim_trailing_whitespace = false
+"
+    )
+}
@@ -0,0 +1,118 @@
+//! The directory a project is created in: resolving the typed path and
+//! deciding what to do when something is already there.
+
+use anyhow::{bail, Context, Result};
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+/// How to treat a target directory that already has content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Existing {
+    /// Stop without touching anything.
+    Refuse,
+    /// Add only the files that are missing; existing files are kept as-is.
+    Merge,
+    /// Write every file, replacing any that are already there.
+    Force,
+}
+
+/// What is already at the target path, if anything.
+pub struct Occupied {
+    /// The directory is in a git repository.
+    pub repo: bool,
+}
+
+/// Expand `~`, make relative paths absolute and drop `.`/`..` components.
+pub fn resolve(path: &Path) -> Result<PathBuf> {
+    let expanded = match path.strip_prefix("~") {
+        Ok(rest) => dirs::home_dir()
+            .context("cannot expand `~`: home directory not found")?
+            .join(rest),
+        Err(_) => path.to_path_buf(),
+    };
+    let absolute = if expanded.is_absolute() {
+        expanded
+    } else {
+        env::current_dir()?.join(expanded)
+    };
+
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    Ok(normalized)
+}
+
+/// `None` when `dir` is missing or empty, i.e. safe to scaffold into.
+pub fn inspect(dir: &Path) -> Result<Option<Occupied>> {
+    if !dir.exists() {
+        return Ok(None);
+    }
+    if !dir.is_dir() {
+        bail!("{} exists and is not a directory.", dir.display());
+    }
+    let mut entries = dir
+        .read_dir()
+        .with_context(|| format!("cannot read {}", dir.display()))?;
+    if entries.next().is_none() {
+        return Ok(None);
+    }
+    Ok(Some(Occupied {
+        repo: repo_root(dir).is_some(),
+    }))
+}
+
+/// `None` when the single file at `path` is free to create.
+pub fn inspect_file(path: &Path) -> Result<Option<Occupied>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    if !path.is_file() {
+        bail!("{} exists and is not a file.", path.display());
+    }
+    Ok(Some(Occupied {
+        repo: repo_root(path).is_some(),
+    }))
+}
+
+/// The top level of the git repository `dir` is in, checked from its
+/// nearest existing ancestor so a directory not created yet counts too.
+pub fn repo_root(dir: &Path) -> Option<PathBuf> {
+    let existing = dir.ancestors().find(|p| p.is_dir())?;
+    let out = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .current_dir(existing)
+        .output()
+        .ok()?;
+    out.status
+        .success()
+        .then(|| PathBuf::from(String::from_utf8_lossy(&out.stdout).trim()))
+}
+
+/// Ask how to proceed with an occupied directory, or a script file that
+/// already exists.
+pub fn ask(dir: &Path, occupied: &Occupied) -> Result<Existing> {
+    let what = if dir.is_file() {
+        "already exists"
+    } else if occupied.repo {
+        "is in an existing git repository"
+    } else {
+        "is not empty"
+    };
+    Ok(
+        cliclack::select(format!("{} {what}. How should it be used?", dir.display()))
+            .item(Existing::Refuse, "Don't use it", "cancel")
+            .item(Existing::Merge, "Merge", "add only missing files")
+            .item(Existing::Force, "Overwrite", "replace files dimos generates")
+            .interact()?,
+    )
+}
@@ -0,0 +1,205 @@
+//! The template language: `{{var}}`, `{{#if cond}} … {{else}} … {{/if}}`
+//! and `\{{` escapes. See the parent module for the full description.
+
+use anyhow::{anyhow, bail, Result};
+
+use super::Vars;
+
+pub enum Cond {
+    Truthy(String),
+    Eq(String, String),
+    Ne(String, String),
+}
+
+impl Cond {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let expr = expr.trim();
+        let cond = if let Some((var, value)) = expr.split_once("!=") {
+            Cond::Ne(var.trim().to_string(), value.trim().to_string())
+        } else if let Some((var, value)) = expr.split_once("==") {
+            Cond::Eq(var.trim().to_string(), value.trim().to_string())
+        } else {
+            Cond::Truthy(expr.to_string())
+        };
+        match &cond {
+            Cond::Truthy(var) | Cond::Eq(var, _) | Cond::Ne(var, _) if var.is_empty() => {
+                bail!("empty condition `{expr}`")
+            }
+            _ => Ok(cond),
+        }
+    }
+
+    pub fn eval(&self, vars: &Vars) -> Result<bool> {
+        let lookup = |var: &str| {
+            vars.get(var)
+                .map(String::as_str)
+                .ok_or_else(|| anyhow!("unknown variable `{var}`"))
+        };
+        Ok(match self {
+            Cond::Truthy(var) => {
+                let value = lookup(var)?;
+                !value.is_empty()
+                    && !value.eq_ignore_ascii_case("false")
+                    && !value.eq_ignore_ascii_case("none")
+            }
+            Cond::Eq(var, expected) => lookup(var)? == expected,
+            Cond::Ne(var, expected) => lookup(var)? != expected,
+        })
+    }
+}
+
+enum Token {
+    Text(String),
+    Var(String),
+    If(Cond),
+    Else,
+    End,
+}
+
+enum Node {
+    Text(String),
+    Var(String),
+    If {
+        cond: Cond,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+/// Render one template string.
+pub fn render(src: &str, vars: &Vars) -> Result<String> {
+    let mut tokens = tokenize(src)?.into_iter();
+    let (nodes, stop) = parse(&mut tokens)?;
+    match stop {
+        None => {}
+        Some(Token::Else) => bail!("`{{{{else}}}}` without `{{{{#if}}}}`"),
+        Some(_) => bail!("`{{{{/if}}}}` without `{{{{#if}}}}`"),
+    }
+    let mut out = String::with_capacity(src.len());
+    eval(&nodes, vars, &mut out)?;
+    Ok(out)
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut rest = src;
+    // Whether `text` starts at the beginning of a line.
+    let mut text_at_line_start = true;
+
+    while let Some(open) = rest.find("{{") {
+        // `\{{` is an escaped, literal `{{`.
+        if rest[..open].ends_with('\\') {
+            text.push_str(&rest[..open - 1]);
+            text.push_str("{{");
+            rest = &rest[open + 2..];
+            continue;
+        }
+        text.push_str(&rest[..open]);
+        let after_open = &rest[open + 2..];
+        let close = after_open
+            .find("}}")
+            .ok_or_else(|| anyhow!("unclosed `{{{{` near `{}`", snippet(&rest[open..])))?;
+        let tag = after_open[..close].trim();
+        rest = &after_open[close + 2..];
+
+        let block = if let Some(expr) = tag.strip_prefix("#if ") {
+            Some(Token::If(Cond::parse(expr)?))
+        } else if tag == "else" {
+            Some(Token::Else)
+        } else if tag == "/if" {
+            Some(Token::End)
+        } else {
+            None
+        };
+
+        let Some(token) = block else {
+            if !text.is_empty() {
+                tokens.push(Token::Text(std::mem::take(&mut text)));
+            }
+            tokens.push(Token::Var(tag.to_string()));
+            text_at_line_start = false;
+            continue;
+        };
+
+        // A block tag alone on its line takes the whole line with it.
+        let line_start = text.rfind('\n').map(|i| i + 1);
+        let before_blank = match line_start {
+            Some(i) => text[i..].trim().is_empty(),
+            None => text_at_line_start && text.trim().is_empty(),
+        };
+        let line_end = rest.find('\n');
+        let after_blank = rest[..line_end.unwrap_or(rest.len())].trim().is_empty();
+        let standalone = before_blank && after_blank;
+        if standalone {
+            text.truncate(line_start.unwrap_or(0));
+            rest = line_end.map_or("", |i| &rest[i + 1..]);
+        }
+        if !text.is_empty() {
+            tokens.push(Token::Text(std::mem::take(&mut text)));
+        }
+        tokens.push(token);
+        text_at_line_start = standalone;
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    Ok(tokens)
+}
+
+/// Parse nodes until `{{else}}`, `{{/if}}` or end of input; returns the
+/// token that stopped parsing.
+fn parse(tokens: &mut impl Iterator<Item = Token>) -> Result<(Vec<Node>, Option<Token>)> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(t) => nodes.push(Node::Text(t)),
+            Token::Var(v) => nodes.push(Node::Var(v)),
+            Token::If(cond) => {
+                let (then, stop) = parse(tokens)?;
+                let otherwise = match stop {
+                    Some(Token::Else) => match parse(tokens)? {
+                        (otherwise, Some(Token::End)) => otherwise,
+                        _ => bail!("`{{{{#if}}}}` without matching `{{{{/if}}}}`"),
+                    },
+                    Some(Token::End) => Vec::new(),
+                    _ => bail!("`{{{{#if}}}}` without matching `{{{{/if}}}}`"),
+                };
+                nodes.push(Node::If {
+                    cond,
+                    then,
+                    otherwise,
+                });
+            }
+            stop @ (Token::Else | Token::End) => return Ok((nodes, Some(stop))),
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn eval(nodes: &[Node], vars: &Vars, out: &mut String) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(t) => out.push_str(t),
+            Node::Var(v) => out.push_str(
+                vars.get(v)
+                    .ok_or_else(|| anyhow!("unknown variable `{v}`"))?,
+            ),
+            Node::If {
+                cond,
+                then,
+                otherwise,
+            } => {
+                let branch = if cond.eval(vars)? { then } else { otherwise };
+                eval(branch, vars, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn snippet(s: &str) -> &str {
+    let end = s.char_indices().nth(20).map_or(s.len(), |(i, _)| i);
+    &s[..end]
+}
@@ -0,0 +1,355 @@
+//! Project templates: a directory of text files rendered with variables.
+//!
+//! A template is a directory containing an optional `template.yaml`
+//! manifest and any number of files. File contents (and `dest` paths) are
+//! rendered with:
+//!
+//! - `{{var}}` — substitution; unknown variables are an error.
+//! - `{{#if cond}} … {{else}} … {{/if}}` — conditional blocks, nestable.
+//!   A block tag alone on its line removes that whole line from the output.
+//! - `\{{` — a literal `{{`.
+//!
+//! Conditions are `var` (set, and not `false`/`none`), `var == value` or
+//! `var != value`.
+//!
+//! Without a manifest `files` list, every file is rendered to the same
+//! relative path. With one, only the listed files are, each optionally
+//! renamed (`dest`) or gated (`when`). The manifest may also declare
+//! `prompts`, asked alongside the built-in questions (see [`prompt`]).
+//!
+//! `--template` accepts, in order of precedence:
+//!
+//! - `git+<url>[#ref]` — cloned, then loaded as a directory;
+//! - a path to a template directory;
+//! - a name under `~/.dimos/templates/`;
+//! - a built-in name.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+mod engine;
+mod prompt;
+
+use engine::{render, Cond};
+pub use prompt::Prompt;
+
+use super::run_in;
+use crate::config;
+
+pub type Vars = BTreeMap<String, String>;
+
+const MANIFEST: &str = "template.yaml";
+
+/// Variables the new-app flow always sets; prompts may not redefine them.
+pub const BUILTIN_VARS: &[&str] = &[
+    "name",
+    "module",
+    "author",
+    "python",
+    "type",
+    "license",
+    "year",
+    "description",
+];
+
+/// Templates compiled into the dimos binary, as (name, files).
+static BUILTIN: &[(&str, &[(&str, &str)])] = &[(
+    "default",
+    &[
+        (
+            MANIFEST,
+            include_str!("../../../../templates/default/template.yaml"),
+        ),
+        (
+            "README.md",
+            include_str!("../../../../templates/default/README.md"),
+        ),
+        (
+            "gitignore",
+            include_str!("../../../../templates/default/gitignore"),
+        ),
+        (
+            "licenses/MIT",
+            include_str!("../../../../templates/default/licenses/MIT"),
+        ),
+        (
+            "licenses/Apache-2.0",
+            include_str!("../../../../templates/default/licenses/Apache-2.0"),
+        ),
+        (
+            "licenses/Apache-2.0.NOTICE",
+            include_str!("../../../../templates/default/licenses/Apache-2.0.NOTICE"),
+        ),
+        (
+            "licenses/GPL-3.0",
+            include_str!("../../../../templates/default/licenses/GPL-3.0"),
+        ),
+        (
+            "licenses/LGPL-3.0",
+            include_str!("../../../../templates/default/licenses/LGPL-3.0"),
+        ),
+        (
+            "licenses/MPL-2.0",
+            include_str!("../../../../templates/default/licenses/MPL-2.0"),
+        ),
+        (
+            "licenses/BSD-2-Clause",
+            include_str!("../../../../templates/default/licenses/BSD-2-Clause"),
+        ),
+        (
+            "licenses/BSD-3-Clause",
+            include_str!("../../../../templates/default/licenses/BSD-3-Clause"),
+        ),
+        (
+            "licenses/ISC",
+            include_str!("../../../../templates/default/licenses/ISC"),
+        ),
+        (
+            "licenses/Unlicense",
+            include_str!("../../../../templates/default/licenses/Unlicense"),
+        ),
+    ],
+)];
+
+// ── manifest ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    description: String,
+    files: Option<Vec<FileRule>>,
+    #[serde(default)]
+    prompts: Vec<Prompt>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileRule {
+    src: String,
+    dest: Option<String>,
+    when: Option<String>,
+}
+
+// ── template ──────────────────────────────────────────────────────────────────
+
+pub struct Template {
+    /// Built-in name or directory path, for messages.
+    pub name: String,
+    manifest: Manifest,
+    /// Relative path → contents, excluding the manifest.
+    files: BTreeMap<String, String>,
+}
+
+impl Template {
+    /// Resolve `--template`; see the module docs for the accepted forms.
+    pub fn resolve(spec: &str) -> Result<Self> {
+        if let Some(source) = spec.strip_prefix("git+") {
+            return Self::from_git(spec, source);
+        }
+        let path = Path::new(spec);
+        if path.is_dir() {
+            return Self::from_dir(path);
+        }
+        let user = user_templates_dir().join(spec);
+        if user.is_dir() {
+            return Self::from_dir(&user);
+        }
+        if let Some(template) = Self::builtin(spec) {
+            return template;
+        }
+        bail!(
+            "Unknown template `{spec}` — not a git+ URL, a directory, a template in {}, \
+             nor a built-in ({}).",
+            user_templates_dir().display(),
+            BUILTIN
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn builtin(name: &str) -> Option<Result<Self>> {
+        let (name, entries) = BUILTIN.iter().find(|(n, _)| *n == name)?;
+        let files = entries
+            .iter()
+            .map(|(rel, text)| (rel.to_string(), text.to_string()))
+            .collect();
+        Some(Self::new(name.to_string(), files))
+    }
+
+    fn from_dir(dir: &Path) -> Result<Self> {
+        Self::load_dir(dir.display().to_string(), dir)
+    }
+
+    fn load_dir(name: String, dir: &Path) -> Result<Self> {
+        let mut files = BTreeMap::new();
+        collect_files(dir, dir, &mut files)?;
+        Self::new(name, files)
+    }
+
+    /// Clone `source` (`<url>[#ref]`) into a scratch directory and load it.
+    /// The clone is removed again once the files are in memory.
+    fn from_git(spec: &str, source: &str) -> Result<Self> {
+        let (url, reference) = match source.split_once('#') {
+            Some((url, reference)) => (url, Some(reference)),
+            None => (source, None),
+        };
+        // Both reach git as arguments; neither may be read as an option.
+        if url.starts_with('-') {
+            bail!("invalid template URL `{url}`");
+        }
+        if reference.is_some_and(|r| r.starts_with('-')) {
+            bail!("invalid template ref in `{spec}`");
+        }
+        let checkout = std::env::temp_dir().join(format!("dimos-template-{}", std::process::id()));
+        if checkout.exists() {
+            fs::remove_dir_all(&checkout)
+                .with_context(|| format!("cannot remove {}", checkout.display()))?;
+        }
+
+        let sp = cliclack::spinner();
+        sp.start(format!("Fetching template {url}…"));
+        let loaded = (|| {
+            let target = checkout.to_string_lossy();
+            run_in(
+                &std::env::temp_dir(),
+                "git",
+                &["clone", "--quiet", "--", url, &target],
+            )?;
+            if let Some(reference) = reference {
+                run_in(&checkout, "git", &["checkout", "--quiet", reference])?;
+            }
+            Self::load_dir(spec.to_string(), &checkout)
+        })();
+        let _ = fs::remove_dir_all(&checkout);
+        match &loaded {
+            Ok(_) => sp.stop(format!("Template fetched: {spec}")),
+            Err(_) => sp.error(format!("Could not fetch template {spec}")),
+        }
+        loaded
+    }
+
+    fn new(name: String, mut files: BTreeMap<String, String>) -> Result<Self> {
+        let manifest = match files.remove(MANIFEST) {
+            Some(text) => serde_yaml::from_str(&text)
+                .with_context(|| format!("invalid {MANIFEST} in template `{name}`"))?,
+            None => Manifest::default(),
+        };
+        for prompt in &manifest.prompts {
+            prompt
+                .check(BUILTIN_VARS)
+                .with_context(|| format!("invalid {MANIFEST} in template `{name}`"))?;
+        }
+        if let Some(rules) = &manifest.files {
+            for rule in rules {
+                if !files.contains_key(&rule.src) {
+                    bail!(
+                        "template `{name}` lists `{}`, which does not exist",
+                        rule.src
+                    );
+                }
+            }
+        }
+        Ok(Self {
+            name,
+            manifest,
+            files,
+        })
+    }
+
+    pub fn description(&self) -> &str {
+        &self.manifest.description
+    }
+
+    /// The template's own questions; each answer becomes a variable.
+    pub fn prompts(&self) -> &[Prompt] {
+        &self.manifest.prompts
+    }
+
+    /// Render every applicable file, as (relative destination, contents).
+    pub fn render_files(&self, vars: &Vars) -> Result<Vec<(String, String)>> {
+        let rules: Vec<(&str, Option<&str>, Option<&str>)> = match &self.manifest.files {
+            Some(rules) => rules
+                .iter()
+                .map(|r| (r.src.as_str(), r.dest.as_deref(), r.when.as_deref()))
+                .collect(),
+            None => self
+                .files
+                .keys()
+                .map(|k| (k.as_str(), None, None))
+                .collect(),
+        };
+
+        let mut rendered = Vec::new();
+        for (src, dest, when) in rules {
+            let context = || format!("template `{}`, file `{src}`", self.name);
+            if let Some(when) = when {
+                if !Cond::parse(when)
+                    .and_then(|c| c.eval(vars))
+                    .with_context(context)?
+                {
+                    continue;
+                }
+            }
+            let dest = render(dest.unwrap_or(src), vars).with_context(context)?;
+            check_dest(&dest).with_context(context)?;
+            let text = render(&self.files[src], vars).with_context(context)?;
+            rendered.push((dest, text));
+        }
+        Ok(rendered)
+    }
+}
+
+/// A rendered destination must be a relative path that stays inside the
+/// project: rollback only knows about the project directory.
+fn check_dest(dest: &str) -> Result<()> {
+    let path = Path::new(dest);
+    let escapes = path.components().any(|c| {
+        matches!(
+            c,
+            Component::ParentDir | Component::RootDir | Component::Prefix(_)
+        )
+    });
+    if dest.trim().is_empty() || path.is_absolute() || escapes {
+        bail!("destination `{dest}` is not a path inside the project");
+    }
+    Ok(())
+}
+
+fn user_templates_dir() -> PathBuf {
+    config::dimos_dir().join("templates")
+}
+
+fn collect_files(root: &Path, dir: &Path, out: &mut BTreeMap<String, String>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let entry = entry?;
+        let path: PathBuf = entry.path();
+        // Symlinks could pull in files from outside the template.
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() || path.file_name().is_some_and(|n| n == ".git") {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_files(root, &path, out)?;
+            continue;
+        }
+        let rel = path
+            .strip_prefix(root)
+            .expect("walked path is under root")
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("{} is not a UTF-8 text file", path.display()))?;
+        out.insert(rel, text);
+    }
+    Ok(())
+}
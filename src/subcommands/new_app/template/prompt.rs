@@ -0,0 +1,197 @@
+//! Extra questions a template declares in its manifest, e.g.
+//!
+//! ```yaml
+//! prompts:
+//!   - key: ros_bridge
+//!     prompt: Include ROS bridge?
+//!     kind: confirm
+//!     default: false
+//!   - key: robot
+//!     prompt: Target robot
+//!     kind: select
+//!     options:
+//!       - { value: go2, label: Unitree Go2 }
+//!       - { value: g1, label: Unitree G1 }
+//! ```
+//!
+//! Each answer becomes a template variable named after `key`. Answers are
+//! strings, like survey answers: confirms are `true`/`false` and
+//! multi-selects are comma-separated.
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use serde_yaml::Value;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptKind {
+    #[default]
+    Text,
+    Select,
+    Multiselect,
+    Confirm,
+    Number,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PromptOption {
+    pub value: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub hint: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Prompt {
+    pub key: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub kind: PromptKind,
+    #[serde(default)]
+    pub options: Vec<PromptOption>,
+    /// Any YAML scalar (or a list, for multi-selects).
+    default: Option<Value>,
+    /// Text answers may be empty unless this is set.
+    #[serde(default)]
+    pub required: bool,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+impl Prompt {
+    /// Reject manifests whose prompts could never be answered validly.
+    pub fn check(&self, reserved: &[&str]) -> Result<()> {
+        let key = &self.key;
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            bail!("prompt key `{key}` must be letters, digits and underscores");
+        }
+        if reserved.contains(&key.as_str()) {
+            bail!("prompt key `{key}` clashes with a built-in template variable");
+        }
+        if matches!(self.kind, PromptKind::Select | PromptKind::Multiselect)
+            && self.options.is_empty()
+        {
+            bail!("prompt `{key}` is a select but has no options");
+        }
+        if let Some(default) = self.default_value() {
+            if let Err(e) = self.validate(&default) {
+                bail!("prompt `{key}` has an invalid default: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn default_value(&self) -> Option<String> {
+        let scalar = |v: &Value| match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Bool(b) => Some(b.to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+        match self.default.as_ref()? {
+            Value::Sequence(items) => Some(
+                items
+                    .iter()
+                    .filter_map(scalar)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            other => scalar(other),
+        }
+    }
+
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let allowed = || {
+            self.options
+                .iter()
+                .map(|o| o.value.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let is_option = |v: &str| self.options.iter().any(|o| o.value == v);
+        match self.kind {
+            PromptKind::Text => {
+                if self.required && value.trim().is_empty() {
+                    return Err("answer cannot be empty".to_string());
+                }
+            }
+            PromptKind::Select => {
+                if !is_option(value) {
+                    return Err(format!("`{value}` is not one of: {}", allowed()));
+                }
+            }
+            PromptKind::Multiselect => {
+                for item in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                    if !is_option(item) {
+                        return Err(format!("`{item}` is not one of: {}", allowed()));
+                    }
+                }
+            }
+            PromptKind::Confirm => {
+                if value != "true" && value != "false" {
+                    return Err(format!("`{value}` is not `true` or `false`"));
+                }
+            }
+            PromptKind::Number => {
+                let Ok(n) = value.trim().parse::<i64>() else {
+                    return Err(format!("`{value}` is not a whole number"));
+                };
+                if self.min.is_some_and(|min| n < min) || self.max.is_some_and(|max| n > max) {
+                    return Err(format!(
+                        "{n} is outside {}..={}",
+                        self.min.map_or(String::new(), |m| m.to_string()),
+                        self.max.map_or(String::new(), |m| m.to_string())
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ask(&self) -> Result<String> {
+        let default = self.default_value();
+        let answer = match self.kind {
+            PromptKind::Select => {
+                let mut select = cliclack::select(&self.prompt);
+                for o in &self.options {
+                    select = select.item(o.value.clone(), o.label.as_ref().unwrap_or(&o.value), &o.hint);
+                }
+                if let Some(default) = default {
+                    select = select.initial_value(default);
+                }
+                select.interact()?
+            }
+            PromptKind::Multiselect => {
+                let mut select = cliclack::multiselect(&self.prompt).required(false);
+                for o in &self.options {
+                    select = select.item(o.value.clone(), o.label.as_ref().unwrap_or(&o.value), &o.hint);
+                }
+                if let Some(default) = default {
+                    let values = default
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                    select = select.initial_values(values);
+                }
+                select.interact()?.join(",")
+            }
+            PromptKind::Confirm => cliclack::confirm(&self.prompt)
+                .initial_value(default.as_deref() == Some("true"))
+                .interact()?
+                .to_string(),
+            PromptKind::Text | PromptKind::Number => {
+                let mut input = cliclack::input(&self.prompt).required(self.required);
+                if let Some(default) = &default {
+                    input = input.default_input(default);
+                }
+                // The validator must be 'static, so it owns a copy.
+                let prompt = self.clone();
+                input.validate(move |s: &String| prompt.validate(s)).interact()?
+            }
+        };
+        Ok(answer)
+    }
+}
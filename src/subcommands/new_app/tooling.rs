@@ -0,0 +1,97 @@
+//! Dev tooling: ruff, pytest and mypy in the `dev` dependency group, their
+//! pyproject.toml settings, and a `tests/` package with a smoke test.
+
+use anyhow::Result;
+use std::fs;
+use toml_edit::{value, Array};
+
+use super::Project;
+
+/// Write the tooling files; returns the paths written.
+pub fn write_files(project: &Project) -> Result<Vec<String>> {
+    let lib = project.entry_module().is_none();
+    let src = project.src_layout();
+    let module = project.module();
+    let mut written = Vec::new();
+
+    // A kept main.py is the user's code, which may not be annotated.
+    let strict = src || !project.keep.contains(&project.dir.join("main.py"));
+    if !src && strict && annotate_main(project)? {
+        written.push("main.py (annotations)".to_string());
+    }
+
+    if let Some(mut pyproject) = project.pyproject()? {
+        pyproject.add_to_group("dev", &["ruff", "pytest", "mypy"])?;
+
+        let target = format!("py{}", project.python_version.replace('.', ""));
+        pyproject.table(&["tool", "ruff"])?["target-version"] = value(target);
+        pyproject.table(&["tool", "ruff", "lint"])?["select"] =
+            value(Array::from_iter(["E", "F", "I", "B", "UP"]));
+
+        let pytest = pyproject.table(&["tool", "pytest", "ini_options"])?;
+        pytest["testpaths"] = value(Array::from_iter(["tests"]));
+        // A flat `main.py` is importable only from the project root.
+        if !src {
+            pytest["pythonpath"] = value(Array::from_iter(["."]));
+        }
+
+        let mypy = pyproject.table(&["tool", "mypy"])?;
+        mypy["python_version"] = value(&project.python_version);
+        mypy["files"] = value(Array::from_iter([
+            if src { "src" } else { "main.py" },
+            "tests",
+        ]));
+        if strict {
+            mypy["disallow_untyped_defs"] = value(true);
+        }
+        mypy["warn_unused_configs"] = value(true);
+
+        pyproject.save()?;
+        written.push("pyproject.toml (dev tools)".to_string());
+    }
+
+    let imported = project.entry_module().unwrap_or_else(|| module.clone());
+    let files = [
+        ("tests/__init__.py".to_string(), String::new()),
+        ("tests/test_smoke.py".to_string(), smoke_test(&imported)),
+    ];
+    for (rel, text) in files {
+        if project.write(&rel, &text)? {
+            written.push(rel);
+        }
+    }
+
+    // PEP 561: tells type checkers the package ships its own annotations.
+    if lib {
+        let rel = format!("src/{module}/py.typed");
+        if project.write(&rel, "")? {
+            written.push(rel);
+        }
+    }
+    Ok(written)
+}
+
+/// `uv init --app` writes an unannotated `def main():`, which
+/// `disallow_untyped_defs` rejects.
+fn annotate_main(project: &Project) -> Result<bool> {
+    let Ok(text) = fs::read_to_string(project.dir.join("main.py")) else {
+        return Ok(false);
+    };
+    if !text.contains("def main():") {
+        return Ok(false);
+    }
+    project.write("main.py", &text.replace("def main():", "def main() -> None:"))
+}
+
+fn smoke_test(module: &str) -> String {
+    format!(
+        r#""""Smoke test: the code imports without errors."""
+
+import importlib
+
+
+def test_import() -> None:
+    importlib.import_module("{module}")
+"#
+    )
+}
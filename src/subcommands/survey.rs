@@ -1,56 +1,290 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use serde_yaml::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::IsTerminal,
+    path::Path,
+};
 
-use crate::{config::ConfigManager, questions::*, ui};
+use crate::{
+    config::{self, Config, ConfigManager},
+    questions::{self, Kind, Question, QUESTIONS},
+    ui,
+};
 
-pub fn run() -> Result<()> {
+pub fn run(only: &[String], all: bool, answers_file: Option<&Path>, set: &[String]) -> Result<()> {
     ui::header(" Dimos — Survey");
 
-    let mut mgr = ConfigManager::load_or_recover()?;
+    let supplied = supplied_answers(answers_file, set)?;
+    // Answers are gathered against a snapshot; the config lock is only taken
+    // to save them, so a survey left open blocks no other command.
+    let mut config = config::load()?;
+    let interactive = std::io::stdin().is_terminal();
+
+    // Entries naming a question that will not be asked are mistakes, not
+    // something to drop silently.
+    let mut effective = config.answers.clone();
+    effective.extend(supplied.iter().map(|(k, v)| (k.clone(), v.clone())));
+    for address in only.iter().chain(supplied.keys()) {
+        check_visible(lookup(address)?, only, &effective)?;
+    }
+
+    // By default only new, changed or invalid questions are (re-)asked.
+    let returning = !config.answers.is_empty();
+    let selective = only.is_empty() && !all;
+    let pending = |q: &Question, config: &Config| {
+        q.needs_answer(config) || supplied.contains_key(q.address)
+    };
+    let candidates: Vec<&Question> = if !only.is_empty() {
+        only.iter().map(|address| lookup(address)).collect::<Result<_>>()?
+    } else {
+        QUESTIONS.iter().collect()
+    };
+    let count = if selective {
+        candidates.iter().filter(|q| pending(q, &config)).count()
+    } else {
+        candidates.len()
+    };
+
+    if count == 0 {
+        ui::outro("Survey is up to date. Use `dimos survey --all` to revisit every answer.");
+        return Ok(());
+    }
 
-    cliclack::note(
-        "Quick intro",
-        "A few questions so Dimos can tailor its suggestions to you.",
-    )?;
-
-    let editor: &str = cliclack::select("What's your editor of choice?")
-        .item("neovim", "Neovim", "")
-        .item("emacs", "Emacs", "")
-        .item("vscode", "VS Code", "")
-        .item("zed", "Zed", "")
-        .item("other", "Other / CLI", "")
-        .interact()?;
-
-    let indentation: &str = cliclack::select("Tabs or spaces?")
-        .item("spaces", "Spaces", "")
-        .item("tabs", "Tabs", "heresy")
-        .interact()?;
-
-    let primary_language: String = cliclack::input("Primary programming language?")
-        .placeholder("e.g. Python, Rust, TypeScript")
-        .interact()?;
-
-    let schedule: &str = cliclack::select("When do you do your best work?")
-        .item("morning", "Early bird", "up before the coffee")
-        .item("night", "Night owl", "when everyone else is asleep")
-        .item("whenever", "Whenever the flow hits", "chaos schedule")
-        .interact()?;
-
-    let debug_style: &str = cliclack::select("How do you debug?")
-        .item("prints", "Print statements", "the classic")
-        .item("debugger", "Proper debugger", "breakpoints and watches")
-        .item("rubber_duck", "Rubber duck", "talking it through")
-        .item("rewrite", "Rewrite until it works", "burn it down")
-        .interact()?;
-
-    mgr.set::<PersonalityEditor>(editor.to_string());
-    mgr.set::<PersonalityIndentation>(indentation.to_string());
-    mgr.set::<PersonalityLanguage>(primary_language);
-    mgr.set::<PersonalitySchedule>(schedule.to_string());
-    mgr.set::<PersonalityDebugStyle>(debug_style.to_string());
+    if interactive && only.is_empty() && supplied.is_empty() {
+        if returning && !all {
+            cliclack::note(
+                "Welcome back",
+                format!(
+                    "{count} new or changed question(s) since your last survey."
+                ),
+            )?;
+        } else {
+            cliclack::note(
+                "Quick intro",
+                "A few questions so Dimos can tailor its suggestions to you.",
+            )?;
+        }
+    }
 
+    // Visibility is evaluated as we go, so a question can depend on an answer
+    // given earlier in this same run.
+    let mut unanswered = Vec::new();
+    let mut answered = Vec::new();
+    for question in candidates {
+        if !question.is_visible(&config.answers)
+            || (selective && !pending(question, &config))
+        {
+            continue;
+        }
+        let answer = match supplied.get(question.address) {
+            Some(value) => value.clone(),
+            None if interactive => {
+                let previous = config.answers.get(question.address).cloned();
+                ask(question, previous.as_deref())?
+            }
+            None => {
+                // Without a TTY, a valid stored answer is kept as-is.
+                if question.needs_answer(&config) {
+                    unanswered.push(question.address);
+                }
+                continue;
+            }
+        };
+        config.answers.insert(question.address.to_string(), answer.clone());
+        config
+            .revisions
+            .insert(question.address.to_string(), question.revision);
+        answered.push((question, answer));
+    }
+
+    if !unanswered.is_empty() {
+        bail!(
+            "No terminal to prompt on, and no answer for: {}.\n\
+             Supply them with --answers <file> or --set <address>=<value>.",
+            unanswered.join(", ")
+        );
+    }
+
+    let mut mgr = ConfigManager::load_or_recover()?;
+    for (question, answer) in answered {
+        mgr.set_answer(question, answer);
+    }
     mgr.save()?;
     cliclack::log::success(format!("Saved → {}", mgr.path().display()))?;
 
     ui::outro(" All done!");
     Ok(())
 }
+
+fn lookup(address: &str) -> Result<&'static Question> {
+    match questions::find(address) {
+        Some(q) => Ok(q),
+        None => bail!(
+            "Unknown question `{address}`. Known questions: {}",
+            QUESTIONS
+                .iter()
+                .map(|q| q.address)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Fail if `question` is conditional and its condition neither holds in
+/// `answers` nor depends on a question asked again in this run (`only`).
+fn check_visible(question: &Question, only: &[String], answers: &HashMap<String, String>) -> Result<()> {
+    let Some(condition) = &question.show_if else {
+        return Ok(());
+    };
+    if condition.holds(answers) || only.iter().any(|a| a == condition.address) {
+        return Ok(());
+    }
+    bail!(
+        "`{}` is only asked when {} is {} — answer that first.",
+        question.address,
+        condition.address,
+        condition.any_of.join(" or ")
+    )
+}
+
+// ── supplied answers ──────────────────────────────────────────────────────────
+
+/// Merge `--answers` and `--set` (which wins), validating every value.
+fn supplied_answers(file: Option<&Path>, set: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut answers = BTreeMap::new();
+
+    if let Some(path) = file {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        let doc: Value = serde_yaml::from_str(&text)
+            .with_context(|| format!("{} is not valid YAML", path.display()))?;
+        flatten("", &doc, &mut answers)
+            .with_context(|| format!("in {}", path.display()))?;
+    }
+
+    for pair in set {
+        let Some((address, value)) = pair.split_once('=') else {
+            bail!("--set expects ADDRESS=VALUE, got `{pair}`");
+        };
+        answers.insert(address.trim().to_string(), value.trim().to_string());
+    }
+
+    for (address, value) in &answers {
+        lookup(address)?
+            .validate(value)
+            .map_err(|e| anyhow::anyhow!("Invalid answer for {address}: {e}"))?;
+    }
+    Ok(answers)
+}
+
+/// Flatten a YAML document into dotted addresses, so both
+/// `personality.editor: neovim` and a nested `personality: {editor: neovim}`
+/// work. Sequences become comma-separated multi-select answers.
+fn flatten(prefix: &str, value: &Value, out: &mut BTreeMap<String, String>) -> Result<()> {
+    let scalar = |v: &Value| -> Option<String> {
+        match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Bool(b) => Some(b.to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    };
+
+    match value {
+        Value::Mapping(map) => {
+            for (key, child) in map {
+                let Some(key) = key.as_str() else {
+                    bail!("answer keys must be strings");
+                };
+                let address = if prefix.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&address, child, out)?;
+            }
+        }
+        Value::Sequence(items) => {
+            let items = items
+                .iter()
+                .map(|item| {
+                    scalar(item)
+                        .with_context(|| format!("{prefix}: list items must be plain values"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            out.insert(prefix.to_string(), items.join(","));
+        }
+        Value::Null if prefix.is_empty() => {}
+        _ if prefix.is_empty() => bail!("expected a mapping of question addresses to answers"),
+        other => match scalar(other) {
+            Some(s) => {
+                out.insert(prefix.to_string(), s);
+            }
+            None => bail!("{prefix}: expected a plain value"),
+        },
+    }
+    Ok(())
+}
+
+// ── prompting ─────────────────────────────────────────────────────────────────
+
+/// Prompt for one question, pre-selecting the previous (or default) answer.
+fn ask(question: &'static Question, previous: Option<&str>) -> Result<String> {
+    // A previous answer that no longer validates (e.g. a removed option) is
+    // not worth pre-selecting.
+    let initial = previous
+        .filter(|p| question.validate(p).is_ok())
+        .or(question.default);
+
+    let answer = match question.kind {
+        Kind::Select => {
+            let mut select = cliclack::select(question.prompt);
+            for c in question.options {
+                select = select.item(c.value, c.label, c.hint);
+            }
+            if let Some(choice) = initial.and_then(|v| question.option(v)) {
+                select = select.initial_value(choice.value);
+            }
+            select.interact()?.to_string()
+        }
+        Kind::MultiSelect => {
+            let mut select = cliclack::multiselect(question.prompt).required(false);
+            for c in question.options {
+                select = select.item(c.value, c.label, c.hint);
+            }
+            if let Some(values) = initial {
+                let values = questions::split_multi(values)
+                    .filter_map(|v| question.option(v))
+                    .map(|c| c.value)
+                    .collect();
+                select = select.initial_values(values);
+            }
+            select.interact()?.join(",")
+        }
+        Kind::Text { placeholder } => {
+            let mut input = cliclack::input(question.prompt).placeholder(placeholder);
+            if let Some(value) = initial {
+                input = input.default_input(value);
+            }
+            input
+                .validate(move |s: &String| question.validate(s))
+                .interact()?
+        }
+        Kind::Confirm => cliclack::confirm(question.prompt)
+            .initial_value(initial == Some("true"))
+            .interact()?
+            .to_string(),
+        Kind::Number { .. } => {
+            let mut input = cliclack::input(question.prompt);
+            if let Some(value) = initial {
+                input = input.default_input(value);
+            }
+            input
+                .validate(move |s: &String| question.validate(s))
+                .interact()?
+        }
+    };
+    Ok(answer)
+}